@@ -0,0 +1,131 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::store::{LookupMap, Vector};
+use near_sdk::{env, ext_contract, log, near_bindgen, require, AccountId, Gas, PanicOnDefault, Promise, PromiseError};
+
+pub use crate::models::*;
+
+mod models;
+
+pub const REGISTER_PROTOCOL_GAS: Gas = Gas::from_tgas(10);
+pub const REQUEST_DECISION_GAS: Gas = Gas::from_tgas(20);
+pub const ON_REQUEST_REGISTERED_GAS: Gas = Gas::from_tgas(5);
+
+/// Methods of the EarthMind contract used by the client.
+#[ext_contract(ext_earthmind)]
+pub trait EarthMind {
+    fn register_protocol(&mut self, culture: String, modules: Vec<Module>) -> RegisterProtocolResult;
+    fn request_governance_decision(&mut self, message: String, options: Option<Vec<String>>) -> RegisterRequestResult;
+}
+
+#[near_bindgen]
+#[derive(BorshDeserialize, BorshSerialize, PanicOnDefault)]
+pub struct Contract {
+    owner: AccountId,
+    earthmind: AccountId,
+    pending_requests: LookupMap<Hash, String>,
+    decisions: LookupMap<Hash, u32>,
+    history: Vector<DecisionRecord>,
+}
+
+#[near_bindgen]
+impl Contract {
+    #[allow(clippy::use_self)]
+    #[init]
+    pub fn new(earthmind: AccountId) -> Self {
+        Self {
+            owner: env::predecessor_account_id(),
+            earthmind,
+            pending_requests: LookupMap::new(b"pending_requests".to_vec()),
+            decisions: LookupMap::new(b"decisions".to_vec()),
+            history: Vector::new(b"history".to_vec()),
+        }
+    }
+
+    /// Registers the client as a protocol in EarthMind. The attached deposit pays the registration fee.
+    #[payable]
+    pub fn register(&mut self, culture: String, modules: Vec<Module>) -> Promise {
+        self.assert_owner();
+
+        ext_earthmind::ext(self.earthmind.clone())
+            .with_attached_deposit(env::attached_deposit())
+            .with_static_gas(REGISTER_PROTOCOL_GAS)
+            .register_protocol(culture, modules)
+    }
+
+    /// Asks EarthMind for a governance decision between `options`, or yes and no when they are not given.
+    /// The attached deposit is sent as the bounty of the request.
+    #[payable]
+    pub fn request_decision(&mut self, message: String, options: Option<Vec<String>>) -> Promise {
+        self.assert_owner();
+
+        ext_earthmind::ext(self.earthmind.clone())
+            .with_attached_deposit(env::attached_deposit())
+            .with_static_gas(REQUEST_DECISION_GAS)
+            .request_governance_decision(message.clone(), options)
+            .then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(ON_REQUEST_REGISTERED_GAS)
+                    .on_request_registered(message),
+            )
+    }
+
+    #[private]
+    pub fn on_request_registered(&mut self, message: String, #[callback_result] result: Result<RegisterRequestResult, PromiseError>) -> bool {
+        let Ok(RegisterRequestResult::Success(request_id)) = result else {
+            log!("Request was not registered: {}", message);
+            return false;
+        };
+
+        self.pending_requests.insert(request_id, message);
+
+        true
+    }
+
+    /// Called by EarthMind when a request of the client is finalized.
+    pub fn on_earthmind_decision(&mut self, request_id: Hash, decision: Decision) {
+        require!(env::predecessor_account_id() == self.earthmind, "Only EarthMind can deliver decisions");
+
+        // @dev A retried callback doesn't record the decision twice
+        if self.decisions.contains_key(&request_id) {
+            log!("Decision already received: {}", request_id);
+            return;
+        }
+
+        let message = self.pending_requests.remove(&request_id).unwrap_or_default();
+
+        self.decisions.insert(request_id.clone(), self.history.len());
+        self.history.push(DecisionRecord {
+            request_id,
+            message,
+            decision,
+            received_at: env::block_timestamp(),
+        });
+    }
+
+    pub fn get_earthmind(&self) -> AccountId {
+        self.earthmind.clone()
+    }
+
+    pub fn get_pending_request(&self, request_id: Hash) -> Option<String> {
+        self.pending_requests.get(&request_id).cloned()
+    }
+
+    pub fn get_decision(&self, request_id: Hash) -> Option<Decision> {
+        let index = self.decisions.get(&request_id)?;
+        self.history.get(*index).map(|record| record.decision.clone())
+    }
+
+    pub fn get_decisions(&self, from_index: u32, limit: u32) -> Vec<DecisionRecord> {
+        self.history.iter().skip(from_index as usize).take(limit as usize).cloned().collect()
+    }
+
+    pub fn get_decision_count(&self) -> u32 {
+        self.history.len()
+    }
+}
+
+impl Contract {
+    fn assert_owner(&self) {
+        require!(env::predecessor_account_id() == self.owner, "Only the owner can call this method");
+    }
+}
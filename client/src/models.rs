@@ -0,0 +1,44 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::I128;
+use near_sdk::serde::{Deserialize, Serialize};
+
+pub type Hash = String;
+
+// @dev The following types mirror the JSON interface of the EarthMind contract
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Module {
+    TextPrompting,
+    ObjectRecognition,
+}
+
+#[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum RegisterProtocolResult {
+    Success,
+    AlreadyRegistered,
+}
+
+#[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum RegisterRequestResult {
+    Success(Hash),
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Decision {
+    pub option: Option<u32>,
+    pub votes: Vec<u32>,
+    pub value: Option<I128>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct DecisionRecord {
+    pub request_id: Hash,
+    pub message: String,
+    pub decision: Decision,
+    pub received_at: u64,
+}
@@ -0,0 +1,201 @@
+use near_sdk::mock::MockAction;
+use near_sdk::test_utils::{get_created_receipts, get_logs, VMContextBuilder};
+use near_sdk::{testing_env, AccountId, NearToken, PromiseError};
+
+use earthmind_client::{Contract, Decision, DecisionRecord, Module, RegisterRequestResult};
+
+const CLIENT: &str = "client.near";
+const EARTHMIND: &str = "earthmind.near";
+const OWNER: &str = "owner.near";
+const MESSAGE: &str = "Should we add this new NFT to our protocol?";
+const REQUEST_ID: &str = "0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726";
+const TIMESTAMP: u64 = 100_000_000_000;
+
+fn account(account_id: &str) -> AccountId {
+    account_id.parse().unwrap()
+}
+
+fn set_context(predecessor: &str, attached_deposit: NearToken) {
+    let mut builder = VMContextBuilder::new();
+    builder
+        .current_account_id(account(CLIENT))
+        .predecessor_account_id(account(predecessor))
+        .block_timestamp(TIMESTAMP)
+        .attached_deposit(attached_deposit);
+
+    testing_env!(builder.build());
+}
+
+fn setup_client() -> Contract {
+    set_context(OWNER, NearToken::from_yoctonear(0));
+    Contract::new(account(EARTHMIND))
+}
+
+fn function_calls() -> Vec<(AccountId, String, NearToken)> {
+    get_created_receipts()
+        .into_iter()
+        .flat_map(|receipt| {
+            receipt.actions.into_iter().filter_map(move |action| match action {
+                MockAction::FunctionCallWeight {
+                    method_name, attached_deposit, ..
+                } => Some((receipt.receiver_id.clone(), String::from_utf8(method_name).unwrap(), attached_deposit)),
+                _ => None,
+            })
+        })
+        .collect()
+}
+
+// @dev Decisions are delivered by EarthMind as JSON, so the client decodes the EarthMind type
+fn earthmind_decision(option: Option<u32>, votes: Vec<u32>) -> Decision {
+    let decision = earthmind_rs::Decision { option, votes, value: None };
+
+    serde_json::from_value(serde_json::to_value(decision).unwrap()).unwrap()
+}
+
+#[test]
+fn test_register_forwards_the_registration_fee() {
+    let mut contract = setup_client();
+
+    set_context(OWNER, NearToken::from_near(5));
+    let _ = contract.register("Governance decision".to_string(), vec![Module::TextPrompting]);
+
+    assert_eq!(
+        function_calls(),
+        vec![(account(EARTHMIND), "register_protocol".to_string(), NearToken::from_near(5))]
+    );
+}
+
+#[test]
+fn test_modules_match_earthmind() {
+    let modules = vec![earthmind_rs::Module::TextPrompting, earthmind_rs::Module::ObjectRecognition];
+
+    let client_modules: Vec<Module> = serde_json::from_value(serde_json::to_value(modules).unwrap()).unwrap();
+
+    assert_eq!(client_modules, vec![Module::TextPrompting, Module::ObjectRecognition]);
+}
+
+#[test]
+fn test_request_decision_forwards_the_bounty() {
+    let mut contract = setup_client();
+
+    set_context(OWNER, NearToken::from_near(2));
+    let _ = contract.request_decision(MESSAGE.to_string(), None);
+
+    assert_eq!(
+        function_calls(),
+        vec![
+            (account(EARTHMIND), "request_governance_decision".to_string(), NearToken::from_near(2)),
+            (account(CLIENT), "on_request_registered".to_string(), NearToken::from_yoctonear(0)),
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Only the owner can call this method")]
+fn test_request_decision_when_caller_is_not_the_owner() {
+    let mut contract = setup_client();
+
+    set_context("someone.near", NearToken::from_yoctonear(0));
+    let _ = contract.request_decision(MESSAGE.to_string(), None);
+}
+
+#[test]
+fn test_on_request_registered_stores_the_pending_request() {
+    let mut contract = setup_client();
+    let id = REQUEST_ID.to_string();
+
+    set_context(CLIENT, NearToken::from_yoctonear(0));
+    let result = contract.on_request_registered(MESSAGE.to_string(), Ok(RegisterRequestResult::Success(id.clone())));
+
+    assert!(result);
+    assert_eq!(contract.get_pending_request(id), Some(MESSAGE.to_string()));
+}
+
+#[test]
+fn test_on_request_registered_stores_the_ids_returned_by_earthmind() {
+    let mut earthmind = earthmind_rs::Contract::new();
+    let mut contract = setup_client();
+
+    // @dev Both requests are sent before the client receives the first callback
+    set_context(CLIENT, NearToken::from_near(5));
+    earthmind.register_protocol("Governance decision".to_string(), vec![earthmind_rs::Module::TextPrompting]);
+    let results: Vec<RegisterRequestResult> = [
+        earthmind.request_governance_decision(MESSAGE.to_string(), None),
+        earthmind.request_governance_decision(MESSAGE.to_string(), None),
+    ]
+    .into_iter()
+    .map(|result| serde_json::from_value(serde_json::to_value(result).unwrap()).unwrap())
+    .collect();
+
+    set_context(CLIENT, NearToken::from_yoctonear(0));
+    for result in results {
+        assert!(contract.on_request_registered(MESSAGE.to_string(), Ok(result)));
+    }
+
+    for nonce in 0..2 {
+        let id = earthmind.get_request_id(account(CLIENT), MESSAGE.to_string(), nonce);
+        assert_eq!(contract.get_pending_request(id), Some(MESSAGE.to_string()));
+    }
+}
+
+#[test]
+fn test_on_request_registered_when_request_failed() {
+    let mut contract = setup_client();
+    let id = REQUEST_ID.to_string();
+
+    set_context(CLIENT, NearToken::from_yoctonear(0));
+    let result = contract.on_request_registered(MESSAGE.to_string(), Err(PromiseError::Failed));
+
+    assert!(!result);
+    assert!(contract.get_pending_request(id).is_none());
+    assert_eq!(get_logs(), vec![format!("Request was not registered: {}", MESSAGE)]);
+}
+
+#[test]
+fn test_on_earthmind_decision_records_the_decision() {
+    let mut contract = setup_client();
+    let id = REQUEST_ID.to_string();
+
+    set_context(CLIENT, NearToken::from_yoctonear(0));
+    contract.on_request_registered(MESSAGE.to_string(), Ok(RegisterRequestResult::Success(id.clone())));
+
+    set_context(EARTHMIND, NearToken::from_yoctonear(0));
+    let decision = earthmind_decision(Some(0), vec![7, 3]);
+    contract.on_earthmind_decision(id.clone(), decision.clone());
+
+    assert!(contract.get_pending_request(id.clone()).is_none());
+    assert_eq!(contract.get_decision(id.clone()), Some(decision.clone()));
+    assert_eq!(contract.get_decision_count(), 1);
+    assert_eq!(
+        contract.get_decisions(0, 10),
+        vec![DecisionRecord {
+            request_id: id,
+            message: MESSAGE.to_string(),
+            decision,
+            received_at: TIMESTAMP,
+        }]
+    );
+}
+
+#[test]
+fn test_on_earthmind_decision_when_decision_is_delivered_twice() {
+    let mut contract = setup_client();
+    let id = REQUEST_ID.to_string();
+
+    set_context(EARTHMIND, NearToken::from_yoctonear(0));
+    contract.on_earthmind_decision(id.clone(), earthmind_decision(Some(0), vec![7, 3]));
+    contract.on_earthmind_decision(id.clone(), earthmind_decision(Some(1), vec![3, 7]));
+
+    assert_eq!(contract.get_decision_count(), 1);
+    assert_eq!(contract.get_decision(id.clone()), Some(earthmind_decision(Some(0), vec![7, 3])));
+    assert_eq!(get_logs(), vec![format!("Decision already received: {}", id)]);
+}
+
+#[test]
+#[should_panic(expected = "Only EarthMind can deliver decisions")]
+fn test_on_earthmind_decision_when_caller_is_not_earthmind() {
+    let mut contract = setup_client();
+
+    set_context(OWNER, NearToken::from_yoctonear(0));
+    contract.on_earthmind_decision(REQUEST_ID.to_string(), earthmind_decision(Some(0), vec![7, 3]));
+}
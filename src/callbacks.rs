@@ -0,0 +1,82 @@
+use near_sdk::{env, ext_contract, log, near_bindgen, PromiseResult};
+
+use crate::*;
+
+/// Interface that a protocol contract implements to receive the decisions of its requests.
+#[ext_contract(ext_protocol)]
+pub trait EarthmindProtocol {
+    fn on_earthmind_decision(&mut self, request_id: Hash, decision: Decision);
+}
+
+#[near_bindgen]
+impl Contract {
+    #[private]
+    pub fn on_decision_delivered(&mut self, request_id: Hash) -> bool {
+        let delivered = env::promise_results_count() == 1 && matches!(env::promise_result(0), PromiseResult::Successful(_));
+
+        let Some(request) = self.requests.get_mut(&request_id) else {
+            return false;
+        };
+
+        if delivered {
+            request.callback_status = Some(DecisionCallbackStatus::Delivered);
+            return true;
+        }
+
+        // @dev A failed callback is recorded so that it can be sent again with retry_decision_callback
+        request.callback_status = Some(DecisionCallbackStatus::Failed);
+
+        let decision_callback_failed_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "1.0.0".to_string(),
+            event: EventLogVariant::DecisionCallbackFailed(vec![DecisionCallbackFailedLog {
+                request_id,
+                protocol: request.sender.clone(),
+            }]),
+        };
+
+        log!(&decision_callback_failed_log.to_string());
+
+        false
+    }
+
+    pub fn retry_decision_callback(&mut self, request_id: Hash) -> RetryCallbackResult {
+        let Some(request) = self.requests.get(&request_id) else {
+            log!("Request is not registered: {}", request_id);
+            return RetryCallbackResult::Fail;
+        };
+
+        if request.callback_status != Some(DecisionCallbackStatus::Failed) {
+            log!("Decision callback has not failed for request: {}", request_id);
+            return RetryCallbackResult::NotFailed;
+        }
+
+        self.send_decision(&request_id);
+
+        RetryCallbackResult::Success
+    }
+
+    pub fn get_decision_callback_status(&self, request_id: Hash) -> Option<DecisionCallbackStatus> {
+        self.requests.get(&request_id)?.callback_status
+    }
+}
+
+impl Contract {
+    /// Calls `on_earthmind_decision` on the protocol that sent the request and
+    /// resolves the result in `on_decision_delivered`.
+    pub(crate) fn send_decision(&mut self, request_id: &Hash) {
+        let request = self.requests.get_mut(request_id).unwrap_or_else(|| panic!("Request not found"));
+        let decision = request.decision.clone().unwrap_or_else(|| panic!("Decision not found"));
+
+        request.callback_status = Some(DecisionCallbackStatus::Pending);
+
+        ext_protocol::ext(request.sender.clone())
+            .with_static_gas(DECISION_CALLBACK_GAS)
+            .on_earthmind_decision(request_id.clone(), decision)
+            .then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(ON_DECISION_DELIVERED_GAS)
+                    .on_decision_delivered(request_id.clone()),
+            );
+    }
+}
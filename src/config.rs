@@ -0,0 +1,54 @@
+use near_sdk::{env, log, near_bindgen};
+
+use crate::*;
+
+#[near_bindgen]
+impl Contract {
+    pub fn get_config(&self) -> Config {
+        self.config.clone()
+    }
+
+    /// Replaces the economic parameters of the contract. Stakes, fees and rewards that were
+    /// already paid are not affected.
+    pub fn update_config(&mut self, config: Config) -> UpdateConfigResult {
+        self.assert_any_role(&[Role::Owner, Role::Admin]);
+
+        if !Self::is_valid_config(&config) {
+            log!("Invalid config: stakes and limits must be positive, slashes below 100, percentages at most 100, the deviation threshold at most 10, the decision threshold at least 50 and the trimmed percentage below 50");
+            return UpdateConfigResult::InvalidConfig;
+        }
+
+        self.config = config.clone();
+
+        let config_updated_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "1.0.0".to_string(),
+            event: EventLogVariant::ConfigUpdated(vec![ConfigUpdatedLog {
+                account: env::predecessor_account_id(),
+                config,
+            }]),
+        };
+
+        log!(&config_updated_log.to_string());
+
+        UpdateConfigResult::Success
+    }
+}
+
+impl Contract {
+    // @dev Slashes stay below 100 so that slashed accounts keep part of their stake while they are registered
+    const fn is_valid_config(config: &Config) -> bool {
+        !config.min_miner_stake.is_zero()
+            && !config.min_validator_stake.is_zero()
+            && config.unbonding_period > 0
+            && config.miner_reward_percentage <= 100
+            && config.bounty_min_revealed_validators > 0
+            && config.miner_slash_percentage < 100
+            && config.validator_slash_percentage < 100
+            && config.validator_deviation_threshold <= 10
+            && config.vote_weight_cap > 0
+            && config.decision_threshold_percentage >= 50
+            && config.decision_threshold_percentage <= 100
+            && config.trimmed_percentage < 50
+    }
+}
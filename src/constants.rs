@@ -1,10 +1,62 @@
-use near_sdk::NearToken;
+use near_sdk::{Gas, NearToken};
+
+use crate::models::{Config, PhaseSchedule, VoteWeighting};
 
 pub const TWO_MINUTES: u64 = 2 * 60 * 1_000_000_000; // 2 minutes in nanoseconds
+pub const ONE_DAY: u64 = 24 * 60 * 60 * 1_000_000_000; // 1 day in nanoseconds
 pub const COMMIT_MINER_DURATION: u64 = TWO_MINUTES;
 pub const REVEAL_MINER_DURATION: u64 = TWO_MINUTES;
 pub const COMMIT_VALIDATOR_DURATION: u64 = TWO_MINUTES;
 pub const REVEAL_VALIDATOR_DURATION: u64 = TWO_MINUTES;
+pub const DEFAULT_PHASE_SCHEDULE: PhaseSchedule = PhaseSchedule {
+    commit_miners: COMMIT_MINER_DURATION,
+    reveal_miners: REVEAL_MINER_DURATION,
+    commit_validators: COMMIT_VALIDATOR_DURATION,
+    reveal_validators: REVEAL_VALIDATOR_DURATION,
+};
+pub const MIN_PHASE_DURATION: u64 = 60 * 1_000_000_000; // 1 minute in nanoseconds
+pub const MAX_PHASE_DURATION: u64 = 7 * ONE_DAY;
 pub const MIN_MINER_STAKE: NearToken = NearToken::from_near(1); // 1 NEAR
 pub const MIN_VALIDATOR_STAKE: NearToken = NearToken::from_near(10); // 10 NEAR
 pub const PROTOCOL_REGISTRATION_FEE: NearToken = NearToken::from_near(5);
+pub const UNBONDING_PERIOD: u64 = ONE_DAY;
+pub const MINER_SLASH_PERCENTAGE: u128 = 10; // 10% of the stake
+pub const VALIDATOR_SLASH_PERCENTAGE: u128 = 10; // 10% of the stake
+pub const VALIDATOR_DEVIATION_THRESHOLD: u32 = 5; // Miners of a ranking missing from the top ten
+pub const REWARD_PER_REQUEST: NearToken = NearToken::from_near(1);
+pub const MINER_REWARD_PERCENTAGE: u128 = 70; // The validators get the rest of the reward
+pub const BOUNTY_MIN_REVEALED_VALIDATORS: u32 = 1; // Default number of revealed validators below which the bounty is refunded to the protocol
+pub const VOTE_WEIGHTING: VoteWeighting = VoteWeighting::Linear; // Default policy to weight validator votes by stake
+pub const VOTE_WEIGHT_UNIT: NearToken = MIN_VALIDATOR_STAKE; // A validator with the minimum stake has a weight of 1
+pub const VOTE_WEIGHT_CAP: u32 = 10; // Default maximum weight with the capped weighting
+pub const POSITION_POINTS: [u32; 10] = [10, 9, 8, 7, 6, 5, 4, 3, 2, 1]; // Borda points of every position in a validator ranking
+pub const DECISION_THRESHOLD_PERCENTAGE: u32 = 50; // Default share of the top ten answers that the winning option must exceed
+pub const DEFAULT_OPTIONS: [&str; 2] = ["Yes", "No"]; // Options of the requests that do not set them
+pub const MAX_OPTIONS: usize = 10;
+pub const MAX_OPTION_LENGTH: usize = 100; // In bytes
+pub const MAX_NUMERIC_VALUE: i128 = 10_i128.pow(36); // Largest magnitude of a numeric bound, so summing the top ten values can't overflow
+pub const MAX_DECIMALS: u8 = 24;
+pub const TRIMMED_PERCENTAGE: u32 = 20; // Default share of the lowest and of the highest values left out of a trimmed mean
+pub const MAX_MESSAGE_LENGTH: usize = 1024; // In bytes, longer answers are revealed as an off-chain payload
+pub const MAX_URI_LENGTH: usize = 512; // In bytes
+pub const DECISION_CALLBACK_GAS: Gas = Gas::from_tgas(10); // Gas for on_earthmind_decision in the protocol contract
+pub const ON_DECISION_DELIVERED_GAS: Gas = Gas::from_tgas(5);
+pub const MIGRATE_GAS: Gas = Gas::from_tgas(100); // Gas for migrate after a self-upgrade
+pub const MAX_SCANNED_REQUESTS: u32 = 100; // Requests of a protocol read by a filtered page of get_requests_by_protocol
+pub const MAX_BACKFILLED_ACCOUNTS: usize = 100; // Accounts added to the registries by a call to backfill_registry
+pub const DEFAULT_CONFIG: Config = Config {
+    min_miner_stake: MIN_MINER_STAKE,
+    min_validator_stake: MIN_VALIDATOR_STAKE,
+    protocol_registration_fee: PROTOCOL_REGISTRATION_FEE,
+    unbonding_period: UNBONDING_PERIOD,
+    reward_per_request: REWARD_PER_REQUEST,
+    miner_reward_percentage: MINER_REWARD_PERCENTAGE,
+    bounty_min_revealed_validators: BOUNTY_MIN_REVEALED_VALIDATORS,
+    miner_slash_percentage: MINER_SLASH_PERCENTAGE,
+    validator_slash_percentage: VALIDATOR_SLASH_PERCENTAGE,
+    validator_deviation_threshold: VALIDATOR_DEVIATION_THRESHOLD,
+    vote_weighting: VOTE_WEIGHTING,
+    vote_weight_cap: VOTE_WEIGHT_CAP,
+    decision_threshold_percentage: DECISION_THRESHOLD_PERCENTAGE,
+    trimmed_percentage: TRIMMED_PERCENTAGE,
+};
@@ -0,0 +1,103 @@
+use near_sdk::json_types::I128;
+use near_sdk::{log, near_bindgen};
+
+use crate::*;
+
+#[near_bindgen]
+impl Contract {
+    pub fn get_decision(&self, request_id: Hash) -> Option<Decision> {
+        self.requests.get(&request_id)?.decision.clone()
+    }
+}
+
+impl Contract {
+    /// Aggregates the revealed answers of the miners in the top ten of a finalized request
+    /// into its decision. An option wins when its answers exceed the decision threshold of the config,
+    /// the values of a numeric request are combined with its aggregation.
+    pub(crate) fn finalize_decision(&mut self, request_id: &Hash) {
+        let threshold_percentage = self.config.decision_threshold_percentage;
+        let trimmed_percentage = self.config.trimmed_percentage;
+        let request = self.requests.get_mut(request_id).unwrap_or_else(|| panic!("Request not found"));
+
+        let decision = request.numeric.map_or_else(
+            || Self::option_decision(request, threshold_percentage),
+            |numeric| Self::numeric_decision(request, numeric.aggregation, trimmed_percentage),
+        );
+
+        request.decision = Some(decision.clone());
+
+        let decision_finalized_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "1.0.0".to_string(),
+            event: EventLogVariant::DecisionFinalized(vec![DecisionFinalizedLog {
+                request_id: request_id.clone(),
+                decision,
+            }]),
+        };
+
+        log!(&decision_finalized_log.to_string());
+    }
+
+    fn option_decision(request: &Request, threshold_percentage: u32) -> Decision {
+        let mut votes = vec![0u32; request.options.len()];
+
+        for (miner, _) in request.top_ten.iter() {
+            if let Some(proposal) = request.miners_proposals.get(miner).filter(|proposal| proposal.is_revealed) {
+                votes[proposal.answer as usize] += 1;
+            }
+        }
+
+        // @dev With a threshold of at least 50 no more than one option can exceed it
+        let total: u32 = votes.iter().sum();
+        let option = votes
+            .iter()
+            .position(|count| count * 100 > threshold_percentage * total)
+            .map(|option| option as u32);
+
+        Decision { option, votes, value: None }
+    }
+
+    fn numeric_decision(request: &Request, aggregation: Aggregation, trimmed_percentage: u32) -> Decision {
+        let mut values: Vec<i128> = request
+            .top_ten
+            .iter()
+            .filter_map(|(miner, _)| request.miner_values.get(miner))
+            .map(|value| value.0)
+            .collect();
+        values.sort_unstable();
+
+        let value = match aggregation {
+            Aggregation::Median => Self::median(&values),
+            Aggregation::TrimmedMean => Self::trimmed_mean(&values, trimmed_percentage),
+        };
+
+        Decision {
+            option: None,
+            votes: Vec::new(),
+            value: value.map(I128),
+        }
+    }
+
+    /// Middle value of `sorted`, or the mean of the two middle values rounded down when their count is even.
+    const fn median(sorted: &[i128]) -> Option<i128> {
+        let middle = sorted.len() / 2;
+
+        match sorted.len() {
+            0 => None,
+            length if length % 2 == 1 => Some(sorted[middle]),
+            _ => Some((sorted[middle - 1] + sorted[middle]).div_euclid(2)),
+        }
+    }
+
+    /// Mean of `sorted` rounded down, without the `trimmed_percentage` lowest and highest values.
+    fn trimmed_mean(sorted: &[i128], trimmed_percentage: u32) -> Option<i128> {
+        let trimmed = sorted.len() * trimmed_percentage as usize / 100;
+        let kept = &sorted[trimmed..sorted.len() - trimmed];
+
+        if kept.is_empty() {
+            return None;
+        }
+
+        Some(kept.iter().sum::<i128>().div_euclid(kept.len() as i128))
+    }
+}
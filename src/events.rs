@@ -1,5 +1,8 @@
+use near_sdk::json_types::I128;
 use near_sdk::serde::{Deserialize, Serialize};
-use near_sdk::AccountId;
+use near_sdk::{AccountId, NearToken};
+
+use crate::models::{AnswerPayload, Config, Decision, PausableFeature, PhaseSchedule, Role, SlashReason, StateVersion};
 use std::fmt;
 
 type Hash = String;
@@ -11,14 +14,38 @@ type Hash = String;
 #[non_exhaustive]
 pub enum EventLogVariant {
     RegisterProtocol(Vec<RegisterProtocolLog>),
+    UpdatePhaseSchedule(Vec<UpdatePhaseScheduleLog>),
     RegisterMiner(Vec<RegisterMinerLog>),
     RegisterValidator(Vec<RegisterValidatorLog>),
     RegisterRequest(Vec<RegisterRequestLog>),
     CommitMiner(Vec<CommitMinerLog>),
     CommitValidator(Vec<CommitValidatorLog>),
     RevealMiner(Vec<RevealMinerLog>),
+    RevealMinerValue(Vec<RevealMinerValueLog>),
     RevealValidator(Vec<RevealValidatorLog>),
     ToptenMiners(Vec<ToptenMinersLog>),
+    IncreaseMinerStake(Vec<IncreaseMinerStakeLog>),
+    IncreaseValidatorStake(Vec<IncreaseValidatorStakeLog>),
+    UnstakeMiner(Vec<UnstakeMinerLog>),
+    UnstakeValidator(Vec<UnstakeValidatorLog>),
+    WithdrawMiner(Vec<WithdrawMinerLog>),
+    WithdrawValidator(Vec<WithdrawValidatorLog>),
+    SlashMiner(Vec<SlashMinerLog>),
+    SlashValidator(Vec<SlashValidatorLog>),
+    DistributeRewards(Vec<DistributeRewardsLog>),
+    ClaimRewards(Vec<ClaimRewardsLog>),
+    RefundBounty(Vec<RefundBountyLog>),
+    DecisionFinalized(Vec<DecisionFinalizedLog>),
+    DecisionCallbackFailed(Vec<DecisionCallbackFailedLog>),
+    ConfigUpdated(Vec<ConfigUpdatedLog>),
+    RoleGranted(Vec<RoleGrantedLog>),
+    RoleRevoked(Vec<RoleRevokedLog>),
+    OwnershipTransferStarted(Vec<OwnershipTransferStartedLog>),
+    OwnershipTransferred(Vec<OwnershipTransferredLog>),
+    WithdrawTreasury(Vec<WithdrawTreasuryLog>),
+    Paused(Vec<PausedLog>),
+    Unpaused(Vec<UnpausedLog>),
+    StateMigrated(Vec<StateMigratedLog>),
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -33,7 +60,7 @@ pub struct EventLog {
 
 impl fmt::Display for EventLog {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("EVENT_JSON:{}", &serde_json::to_string(self).map_err(|_| fmt::Error)?))
+        f.write_fmt(format_args!("EVENT_JSON:{}", serde_json::to_string(self).map_err(|_| fmt::Error)?))
     }
 }
 
@@ -43,6 +70,13 @@ pub struct RegisterProtocolLog {
     pub account: AccountId,
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct UpdatePhaseScheduleLog {
+    pub account: AccountId,
+    pub schedule: PhaseSchedule,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(crate = "near_sdk::serde")]
 pub struct RegisterMinerLog {
@@ -79,8 +113,22 @@ pub struct CommitValidatorLog {
 #[serde(crate = "near_sdk::serde")]
 pub struct RevealMinerLog {
     pub request_id: String,
-    pub answer: bool,
-    pub message: String,
+    pub answer: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload: Option<AnswerPayload>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RevealMinerValueLog {
+    pub request_id: String,
+    pub value: I128,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload: Option<AnswerPayload>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -95,5 +143,172 @@ pub struct RevealValidatorLog {
 #[serde(crate = "near_sdk::serde")]
 pub struct ToptenMinersLog {
     pub request_id: String,
-    pub topten: Vec<(AccountId, i32)>,
+    pub topten: Vec<(AccountId, u32)>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct IncreaseMinerStakeLog {
+    pub miner: AccountId,
+    pub amount: NearToken,
+    pub stake: NearToken,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct IncreaseValidatorStakeLog {
+    pub validator: AccountId,
+    pub amount: NearToken,
+    pub stake: NearToken,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct UnstakeMinerLog {
+    pub miner: AccountId,
+    pub amount: NearToken,
+    pub unlock_time: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct UnstakeValidatorLog {
+    pub validator: AccountId,
+    pub amount: NearToken,
+    pub unlock_time: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct WithdrawMinerLog {
+    pub miner: AccountId,
+    pub amount: NearToken,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct WithdrawValidatorLog {
+    pub validator: AccountId,
+    pub amount: NearToken,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct SlashMinerLog {
+    pub request_id: String,
+    pub miner: AccountId,
+    pub amount: NearToken,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct SlashValidatorLog {
+    pub request_id: String,
+    pub validator: AccountId,
+    pub amount: NearToken,
+    pub reason: SlashReason,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct DistributeRewardsLog {
+    pub request_id: String,
+    pub miners: Vec<AccountId>,
+    pub miner_reward: NearToken,
+    pub validators: Vec<AccountId>,
+    pub validator_reward: NearToken,
+    pub bounty: NearToken,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ClaimRewardsLog {
+    pub account: AccountId,
+    pub amount: NearToken,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RefundBountyLog {
+    pub request_id: String,
+    pub account: AccountId,
+    pub amount: NearToken,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct DecisionFinalizedLog {
+    pub request_id: String,
+    pub decision: Decision,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct DecisionCallbackFailedLog {
+    pub request_id: String,
+    pub protocol: AccountId,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ConfigUpdatedLog {
+    pub account: AccountId,
+    pub config: Config,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RoleGrantedLog {
+    pub account: AccountId,
+    pub role: Role,
+    pub granted_by: AccountId,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RoleRevokedLog {
+    pub account: AccountId,
+    pub role: Role,
+    pub revoked_by: AccountId,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct OwnershipTransferStartedLog {
+    pub owner: AccountId,
+    pub pending_owner: AccountId,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct OwnershipTransferredLog {
+    pub previous_owner: AccountId,
+    pub owner: AccountId,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct WithdrawTreasuryLog {
+    pub account: AccountId,
+    pub amount: NearToken,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PausedLog {
+    pub feature: PausableFeature,
+    pub account: AccountId,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct UnpausedLog {
+    pub feature: PausableFeature,
+    pub account: AccountId,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct StateMigratedLog {
+    pub version: StateVersion,
 }
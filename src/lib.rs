@@ -1,23 +1,58 @@
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::I128;
 use near_sdk::store::LookupMap;
-use near_sdk::{env, log, near_bindgen, require, AccountId, PanicOnDefault};
+use near_sdk::{env, log, near_bindgen, require, AccountId, NearToken, PanicOnDefault, Promise};
 use std::collections::HashSet;
 
+use crate::registry::Registry;
+
+use crate::storage::{MINER_PROPOSALS_PREFIX, MINER_VALUES_PREFIX, SCORE_BREAKDOWN_PREFIX, VALIDATOR_PROPOSALS_PREFIX, VOTES_FOR_MINERS_PREFIX};
+
+pub use crate::callbacks::EarthmindProtocol;
 pub use crate::constants::*;
 pub use crate::events::*;
 pub use crate::models::*;
 
+mod callbacks;
+mod config;
 mod constants;
+mod decision;
 mod events;
 mod models;
+mod pause;
+mod registry;
+mod rewards;
+mod roles;
+mod schedule;
+mod settlement;
+mod staking;
+mod storage;
+mod upgrade;
+mod views;
+mod voting;
 
 #[near_bindgen]
 #[derive(BorshDeserialize, BorshSerialize, PanicOnDefault)]
 pub struct Contract {
-    protocols: LookupMap<AccountId, Protocol>,
-    requests: LookupMap<Hash, Request>,
+    protocols: LookupMap<AccountId, VersionedProtocol>,
+    requests: LookupMap<Hash, VersionedRequest>,
     miners: LookupMap<AccountId, Stake>,
     validators: LookupMap<AccountId, Stake>,
+    miners_unbonding: LookupMap<AccountId, UnbondingStake>,
+    validators_unbonding: LookupMap<AccountId, UnbondingStake>,
+    miner_commits: LookupMap<AccountId, Vec<Hash>>,
+    validator_commits: LookupMap<AccountId, Vec<Hash>>,
+    treasury: NearToken,
+    rewards: LookupMap<AccountId, NearToken>,
+    miner_registry: Registry,
+    validator_registry: Registry,
+    protocol_requests: LookupMap<AccountId, Vec<Hash>>,
+    owner: AccountId,
+    pending_owner: Option<AccountId>,
+    roles: LookupMap<AccountId, Vec<Role>>,
+    config: Config,
+    paused: PauseState,
+    version: StateVersion,
 }
 
 #[near_bindgen]
@@ -30,19 +65,38 @@ impl Contract {
             requests: LookupMap::new(b"requests".to_vec()),
             miners: LookupMap::new(b"miners".to_vec()),
             validators: LookupMap::new(b"validators".to_vec()),
+            miners_unbonding: LookupMap::new(b"miners_unbonding".to_vec()),
+            validators_unbonding: LookupMap::new(b"validators_unbonding".to_vec()),
+            miner_commits: LookupMap::new(b"miner_commits".to_vec()),
+            validator_commits: LookupMap::new(b"validator_commits".to_vec()),
+            treasury: NearToken::from_yoctonear(0),
+            rewards: LookupMap::new(b"rewards".to_vec()),
+            miner_registry: Registry::new(b"miner_registry"),
+            validator_registry: Registry::new(b"validator_registry"),
+            protocol_requests: LookupMap::new(b"protocol_requests".to_vec()),
+            owner: env::predecessor_account_id(),
+            pending_owner: None,
+            roles: LookupMap::new(b"roles".to_vec()),
+            config: DEFAULT_CONFIG,
+            paused: PauseState::default(),
+            version: StateVersion::V1,
         }
     }
 
+    #[payable]
     pub fn register_protocol(&mut self, culture: String, modules: Vec<Module>) -> RegisterProtocolResult {
+        self.assert_not_paused(PausableFeature::Registration);
+
         let new_account = env::predecessor_account_id();
         let registration_fee = env::attached_deposit();
 
-        if registration_fee < PROTOCOL_REGISTRATION_FEE {
+        if registration_fee < self.config.protocol_registration_fee {
             panic!("Deposit is less than the required to register");
         }
 
         if self.is_protocol_registered(new_account.clone()) {
             log!("Attempted to register an already registered account: {}", new_account);
+            Promise::new(new_account).transfer(registration_fee);
             return RegisterProtocolResult::AlreadyRegistered;
         }
 
@@ -51,9 +105,13 @@ impl Contract {
             culture,
             modules,
             registration_fee,
+            schedule: DEFAULT_PHASE_SCHEDULE,
         };
 
-        self.protocols.insert(new_account.clone(), new_protocol);
+        self.protocols.insert(new_account.clone(), new_protocol.into());
+
+        // @dev Registration fees fund the rewards of the participants
+        self.treasury = self.treasury.saturating_add(registration_fee);
 
         let register_protocol_log = EventLog {
             standard: "emip001".to_string(),
@@ -70,21 +128,26 @@ impl Contract {
         self.protocols.contains_key(&account)
     }
 
+    #[payable]
     pub fn register_miner(&mut self) -> RegisterMinerResult {
+        self.assert_not_paused(PausableFeature::Registration);
+
         let new_miner_id = env::predecessor_account_id();
         let deposit = env::attached_deposit();
 
-        if deposit < MIN_MINER_STAKE {
+        if deposit < self.config.min_miner_stake {
             panic!("Miner deposit is less than the minimum stake");
         }
 
         // @dev Validate the miner is not already registered
         if self.is_miner_registered(new_miner_id.clone()) {
             log!("Attempted to register an already registered miner: {}", new_miner_id);
+            Promise::new(new_miner_id).transfer(deposit);
             return RegisterMinerResult::AlreadyRegistered;
         }
 
         self.miners.insert(new_miner_id.clone(), deposit);
+        self.miner_registry.insert(new_miner_id.clone(), env::block_timestamp());
 
         let register_miner_log = EventLog {
             standard: "emip001".to_string(),
@@ -101,20 +164,25 @@ impl Contract {
         self.miners.contains_key(&miner_id)
     }
 
+    #[payable]
     pub fn register_validator(&mut self) -> RegisterValidatorResult {
+        self.assert_not_paused(PausableFeature::Registration);
+
         let new_validator_id = env::predecessor_account_id();
         let deposit = env::attached_deposit();
 
-        if deposit < MIN_VALIDATOR_STAKE {
+        if deposit < self.config.min_validator_stake {
             panic!("Validator deposit is less than the minimum stake");
         }
 
         if self.is_validator_registered(new_validator_id.clone()) {
             log!("Attempted to register an already registered validator: {}", new_validator_id);
+            Promise::new(new_validator_id).transfer(deposit);
             return RegisterValidatorResult::AlreadyRegistered;
         }
 
         self.validators.insert(new_validator_id.clone(), deposit);
+        self.validator_registry.insert(new_validator_id.clone(), env::block_timestamp());
 
         let register_validator_log = EventLog {
             standard: "emip001".to_string(),
@@ -131,78 +199,183 @@ impl Contract {
         self.validators.contains_key(&validator_id)
     }
 
-    pub fn request_governance_decision(&mut self, message: String) -> RegisterRequestResult {
-        let sender_account = env::predecessor_account_id();
+    #[payable]
+    pub fn request_governance_decision(&mut self, message: String, options: Option<Vec<String>>) -> RegisterRequestResult {
+        self.assert_not_paused(PausableFeature::Requests);
+
+        let options = options.unwrap_or_else(|| DEFAULT_OPTIONS.map(String::from).to_vec());
+        Self::assert_valid_options(&options);
+
+        self.register_request(message, options, None)
+    }
+
+    /// Requests a decision answered with a number between the bounds of `answer`.
+    #[payable]
+    pub fn request_numeric_decision(&mut self, message: String, answer: NumericAnswer) -> RegisterRequestResult {
+        self.assert_not_paused(PausableFeature::Requests);
+
+        Self::assert_valid_numeric_answer(&answer);
+
+        self.register_request(message, Vec::new(), Some(answer))
+    }
+
+    pub fn get_request_by_id(&self, request_id: Hash) -> bool {
+        self.requests.contains_key(&request_id)
+    }
 
-        let concatenated_answer = format!("{}{}", sender_account, message);
-        let new_request_id = env::keccak256(concatenated_answer.as_bytes());
-        let new_request_id_hex = hex::encode(new_request_id);
+    fn register_request(&mut self, message: String, options: Vec<String>, numeric: Option<NumericAnswer>) -> RegisterRequestResult {
+        let sender_account = env::predecessor_account_id();
+        let bounty = env::attached_deposit();
 
         //@dev verify that user is registerd in the protocol
         if !self.is_protocol_registered(sender_account.clone()) {
             panic!("Account unregistered: {}", sender_account);
         }
 
-        //@dev Validate the request is not already registered
-        if self.get_request_by_id(new_request_id_hex.clone()) {
-            log!("Attempted to register an already registered request: {}", new_request_id_hex);
-            return RegisterRequestResult::AlreadyRegistered;
-        }
+        let new_request_id_hex = Self::derive_request_id(&sender_account, &message, self.request_nonce(&sender_account));
+
+        let schedule = self.protocols.get(&sender_account).map_or(DEFAULT_PHASE_SCHEDULE, |protocol| protocol.schedule);
 
         let new_request = Request {
-            sender: sender_account,
+            sender: sender_account.clone(),
             request_id: new_request_id_hex.clone(),
             start_time: env::block_timestamp(),
-            miners_proposals: LookupMap::new(b"miner_proposal".to_vec()),
-            validators_proposals: LookupMap::new(b"validator_proposal".to_vec()),
-            votes_for_miners: LookupMap::new(b"votes_miners".to_vec()),
+            miners_proposals: LookupMap::new(Self::request_prefix(&new_request_id_hex, MINER_PROPOSALS_PREFIX)),
+            validators_proposals: LookupMap::new(Self::request_prefix(&new_request_id_hex, VALIDATOR_PROPOSALS_PREFIX)),
+            votes_for_miners: LookupMap::new(Self::request_prefix(&new_request_id_hex, VOTES_FOR_MINERS_PREFIX)),
+            score_breakdown: LookupMap::new(Self::request_prefix(&new_request_id_hex, SCORE_BREAKDOWN_PREFIX)),
             miner_keys: Vec::new(),
             top_ten: Vec::new(),
+            committed_miners: Vec::new(),
+            committed_validators: Vec::new(),
+            miners_settled: false,
+            validators_settled: false,
+            is_finalized: false,
+            bounty,
+            decision: None,
+            callback_status: None,
+            schedule,
+            options,
+            numeric,
+            miner_values: LookupMap::new(Self::request_prefix(&new_request_id_hex, MINER_VALUES_PREFIX)),
         };
 
         // @dev We store the key of the request as the hash of the message
-        self.requests.insert(new_request_id_hex.clone(), new_request);
+        self.requests.insert(new_request_id_hex.clone(), new_request.into());
+
+        match self.protocol_requests.get_mut(&sender_account) {
+            Some(request_ids) => request_ids.push(new_request_id_hex.clone()),
+            None => {
+                self.protocol_requests.insert(sender_account, vec![new_request_id_hex.clone()]);
+            }
+        }
 
         let register_request_log = EventLog {
             standard: "emip001".to_string(),
             version: "1.0.0".to_string(),
             event: EventLogVariant::RegisterRequest(vec![RegisterRequestLog {
-                request_id: new_request_id_hex,
+                request_id: new_request_id_hex.clone(),
             }]),
         };
 
         log!(&register_request_log.to_string());
 
-        RegisterRequestResult::Success
+        RegisterRequestResult::Success(new_request_id_hex)
     }
 
-    pub fn get_request_by_id(&self, request_id: Hash) -> bool {
-        self.requests.contains_key(&request_id)
+    /// Id of the request number `nonce` of `sender`: the hash of the borsh encoding of the sender, the message and the nonce.
+    ///
+    /// The encoding is length prefixed, so no other sender, message or nonce has it, and its second byte is always 0, so it
+    /// is never the sender and the message that the ids of the baseline requests were derived from.
+    fn derive_request_id(sender: &AccountId, message: &str, nonce: u64) -> Hash {
+        let encoded = borsh::to_vec(&(sender, message, nonce)).unwrap_or_else(|_| panic!("Failed to encode the request id"));
+
+        hex::encode(env::keccak256(&encoded))
+    }
+
+    /// Nonce of the next request of `sender`, which is the number of requests it has created. Requests of the
+    /// baseline contract aren't counted, their ids are derived without a nonce and can't be the ids of new requests.
+    fn request_nonce(&self, sender: &AccountId) -> u64 {
+        self.protocol_requests.get(sender).map_or(0, |request_ids| request_ids.len() as u64)
     }
 
     fn get_request_by_id_mut(&mut self, request_id: Hash) -> Option<&mut Request> {
-        self.requests.get_mut(&request_id)
+        self.requests.get_mut(&request_id).map(|request| &mut **request)
+    }
+
+    fn assert_valid_options(options: &[String]) {
+        require!(
+            (2..=MAX_OPTIONS).contains(&options.len()),
+            format!("A request needs between 2 and {} options", MAX_OPTIONS)
+        );
+        require!(
+            options.iter().all(|option| !option.is_empty() && option.len() <= MAX_OPTION_LENGTH),
+            format!("Options must have between 1 and {} bytes", MAX_OPTION_LENGTH)
+        );
+        require!(options.iter().collect::<HashSet<_>>().len() == options.len(), "Options must be unique");
     }
 
-    fn get_stage(start_time: u64) -> RequestState {
+    /// Content hashes are 32 bytes encoded in hex, like the hashes of the answers.
+    fn is_valid_content_hash(content_hash: &str) -> bool {
+        content_hash.len() == 64 && content_hash.bytes().all(|byte| byte.is_ascii_hexdigit())
+    }
+
+    fn assert_valid_numeric_answer(answer: &NumericAnswer) {
+        require!(answer.min.0 < answer.max.0, "The minimum must be lower than the maximum");
+        require!(
+            answer.min.0 >= -MAX_NUMERIC_VALUE && answer.max.0 <= MAX_NUMERIC_VALUE,
+            format!("Bounds must be between -{0} and {0}", MAX_NUMERIC_VALUE)
+        );
+        require!(
+            answer.decimals <= MAX_DECIMALS,
+            format!("A numeric answer can have at most {} decimals", MAX_DECIMALS)
+        );
+    }
+
+    /// Stage of `request` at the current block. A finalized request is ended, so that requests closed by
+    /// the migration no longer accept commits and reveals while their stages would still be running.
+    fn get_stage(request: &Request) -> RequestState {
+        let start_time = request.start_time;
+        let schedule = &request.schedule;
         let elapsed = env::block_timestamp() - start_time;
 
-        if start_time == 0 {
+        if request.is_finalized {
+            RequestState::Ended
+        } else if start_time == 0 {
             RequestState::NonStarted
-        } else if elapsed < COMMIT_MINER_DURATION {
+        } else if elapsed < schedule.commit_miners {
             RequestState::CommitMiners
-        } else if elapsed < COMMIT_MINER_DURATION + REVEAL_MINER_DURATION {
+        } else if elapsed < schedule.commit_miners + schedule.reveal_miners {
             RequestState::RevealMiners
-        } else if elapsed < COMMIT_MINER_DURATION + REVEAL_MINER_DURATION + COMMIT_VALIDATOR_DURATION {
+        } else if elapsed < schedule.commit_miners + schedule.reveal_miners + schedule.commit_validators {
             RequestState::CommitValidators
-        } else if elapsed < COMMIT_MINER_DURATION + REVEAL_MINER_DURATION + COMMIT_VALIDATOR_DURATION + REVEAL_VALIDATOR_DURATION {
+        } else if elapsed < schedule.commit_miners + schedule.reveal_miners + schedule.commit_validators + schedule.reveal_validators {
             RequestState::RevealValidators
         } else {
             RequestState::Ended
         }
     }
 
-    pub fn hash_miner_answer(self, request_id: Hash, answer: bool, message: String) -> Hash {
+    fn compute_top_ten(request: &Request) -> Vec<(AccountId, u32)> {
+        let mut vote_result = Vec::new();
+
+        for miner_keys in request.miner_keys.iter() {
+            if let Some(votes) = request.votes_for_miners.get(miner_keys) {
+                vote_result.push((miner_keys.clone(), *votes));
+            }
+        }
+
+        vote_result.sort_by_key(|a| std::cmp::Reverse(a.1));
+
+        vote_result.into_iter().take(10).collect()
+    }
+
+    pub fn hash_miner_answer(self, request_id: Hash, answer: u32, message: String) -> Hash {
+        require!(
+            message.len() <= MAX_MESSAGE_LENGTH,
+            format!("Message is longer than {} bytes", MAX_MESSAGE_LENGTH)
+        );
+
         let miner = env::predecessor_account_id();
 
         let concatenated_answer = format!("{}{}{}{}", request_id, miner, answer, message);
@@ -212,7 +385,24 @@ impl Contract {
         hex::encode(value)
     }
 
+    pub fn hash_miner_value(self, request_id: Hash, value: I128, message: String) -> Hash {
+        require!(
+            message.len() <= MAX_MESSAGE_LENGTH,
+            format!("Message is longer than {} bytes", MAX_MESSAGE_LENGTH)
+        );
+
+        let miner = env::predecessor_account_id();
+
+        let concatenated_answer = format!("{}{}{}{}", request_id, miner, value.0, message);
+        let value = env::keccak256(concatenated_answer.as_bytes());
+
+        //@dev Return the hash of the answer
+        hex::encode(value)
+    }
+
     pub fn commit_by_miner(&mut self, request_id: Hash, answer: Hash) -> CommitMinerResult {
+        self.assert_not_paused(PausableFeature::Commit);
+
         let miner = env::predecessor_account_id();
 
         if !self.is_miner_registered(miner.clone()) {
@@ -222,7 +412,7 @@ impl Contract {
 
         match self.get_request_by_id_mut(request_id.clone()) {
             Some(request) => {
-                assert_eq!(Self::get_stage(request.start_time), RequestState::CommitMiners, "Not at CommitMiners stage");
+                assert_eq!(Self::get_stage(request), RequestState::CommitMiners, "Not at CommitMiners stage");
 
                 if request.miners_proposals.get(&miner).is_some() {
                     log!("This miner have a commit answer: {}", miner);
@@ -231,12 +421,16 @@ impl Contract {
 
                 let proposal = MinerProposal {
                     proposal_hash: answer.clone(),
-                    answer: false,
+                    answer: 0,
                     is_revealed: false,
                 };
 
                 // @dev Insert miners_proposals using a mut reference
-                request.miners_proposals.insert(miner, proposal);
+                request.miners_proposals.insert(miner.clone(), proposal);
+                request.committed_miners.push(miner.clone());
+
+                // @dev Track the commit so the miner can't withdraw its stake before revealing
+                Self::add_pending_commit(&mut self.miner_commits, miner, request_id.clone());
 
                 let commit_miner_log = EventLog {
                     standard: "emip001".to_string(),
@@ -276,6 +470,8 @@ impl Contract {
     }
 
     pub fn commit_by_validator(&mut self, request_id: String, answer: Hash) -> CommitValidatorResult {
+        self.assert_not_paused(PausableFeature::Commit);
+
         let validator = env::predecessor_account_id();
 
         if !self.is_validator_registered(validator.clone()) {
@@ -285,11 +481,7 @@ impl Contract {
 
         match self.get_request_by_id_mut(request_id.clone()) {
             Some(request) => {
-                assert_eq!(
-                    Self::get_stage(request.start_time),
-                    RequestState::CommitValidators,
-                    "Not at CommitValidator stage"
-                );
+                assert_eq!(Self::get_stage(request), RequestState::CommitValidators, "Not at CommitValidator stage");
 
                 if request.validators_proposals.get(&validator).is_some() {
                     log!("This validator have a commit answer: {}", validator);
@@ -303,7 +495,11 @@ impl Contract {
                 };
 
                 // @dev Insert miners_proposals using a mut reference
-                request.validators_proposals.insert(validator, proposal);
+                request.validators_proposals.insert(validator.clone(), proposal);
+                request.committed_validators.push(validator.clone());
+
+                // @dev Track the commit so the validator can't withdraw its stake before revealing
+                Self::add_pending_commit(&mut self.validator_commits, validator, request_id.clone());
 
                 let commit_validator_log = EventLog {
                     standard: "emip001".to_string(),
@@ -322,7 +518,28 @@ impl Contract {
         }
     }
 
-    pub fn reveal_by_miner(&mut self, request_id: String, answer: bool, message: String) -> RevealMinerResult {
+    pub fn reveal_by_miner(&mut self, request_id: String, answer: u32, message: String) -> RevealMinerResult {
+        self.reveal_miner_answer(request_id, MinerAnswer::Option(answer), MinerMessage::Inline(message))
+    }
+
+    /// Reveals the fixed-point value committed by a miner for a numeric request.
+    pub fn reveal_value_by_miner(&mut self, request_id: String, value: I128, message: String) -> RevealMinerResult {
+        self.reveal_miner_answer(request_id, MinerAnswer::Value(value.0), MinerMessage::Inline(message))
+    }
+
+    /// Reveals an answer whose reasoning is stored off chain. The commit hashes the content hash followed by the uri as the message.
+    pub fn reveal_by_miner_with_payload(&mut self, request_id: String, answer: u32, payload: AnswerPayload) -> RevealMinerResult {
+        self.reveal_miner_answer(request_id, MinerAnswer::Option(answer), MinerMessage::Payload(payload))
+    }
+
+    /// Reveals a value whose reasoning is stored off chain. The commit hashes the content hash followed by the uri as the message.
+    pub fn reveal_value_by_miner_with_payload(&mut self, request_id: String, value: I128, payload: AnswerPayload) -> RevealMinerResult {
+        self.reveal_miner_answer(request_id, MinerAnswer::Value(value.0), MinerMessage::Payload(payload))
+    }
+
+    fn reveal_miner_answer(&mut self, request_id: String, answer: MinerAnswer, message: MinerMessage) -> RevealMinerResult {
+        self.assert_not_paused(PausableFeature::Reveal);
+
         let miner = env::predecessor_account_id();
 
         if !self.is_miner_registered(miner.clone()) {
@@ -330,32 +547,64 @@ impl Contract {
             return RevealMinerResult::Fail;
         }
 
+        let hashed_message = match &message {
+            MinerMessage::Inline(message) if message.len() > MAX_MESSAGE_LENGTH => {
+                log!("Message is longer than {} bytes", MAX_MESSAGE_LENGTH);
+                return RevealMinerResult::Fail;
+            }
+            MinerMessage::Payload(payload) if !Self::is_valid_content_hash(&payload.content_hash) => {
+                log!("Invalid content hash: {}", payload.content_hash);
+                return RevealMinerResult::Fail;
+            }
+            MinerMessage::Payload(payload) if payload.uri.is_empty() || payload.uri.len() > MAX_URI_LENGTH => {
+                log!("URI must have between 1 and {} bytes", MAX_URI_LENGTH);
+                return RevealMinerResult::Fail;
+            }
+            MinerMessage::Inline(message) => message.clone(),
+            MinerMessage::Payload(payload) => format!("{}{}", payload.content_hash, payload.uri),
+        };
+
         if self.get_request_by_id_mut(request_id.clone()).is_none() {
             log!("Request is not registered: {}", request_id);
             return RevealMinerResult::Fail;
         }
 
-        let complete_request = self
-            .get_request_by_id_mut(request_id.clone())
-            .map_or_else(|| panic!("Request not found"), |request| request);
+        let complete_request = self.get_request_by_id_mut(request_id.clone()).unwrap_or_else(|| panic!("Request not found"));
 
-        assert_eq!(
-            Self::get_stage(complete_request.start_time),
-            RequestState::RevealMiners,
-            "Not at RevealMiners stage"
-        );
+        assert_eq!(Self::get_stage(complete_request), RequestState::RevealMiners, "Not at RevealMiners stage");
 
         let save_proposal = complete_request
             .miners_proposals
             .get_mut(&miner)
-            .map_or_else(|| panic!("proposal not found"), |proposal| proposal);
+            .unwrap_or_else(|| panic!("proposal not found"));
 
         if save_proposal.is_revealed {
             log!("Proposal already revealed");
             return RevealMinerResult::Fail;
         }
 
-        let concatenated_answer = format!("{}{}{}{}", request_id, miner, answer, message);
+        let hashed_answer = match (answer, complete_request.numeric) {
+            (MinerAnswer::Option(option), None) if (option as usize) < complete_request.options.len() => option.to_string(),
+            (MinerAnswer::Value(value), Some(numeric)) if (numeric.min.0..=numeric.max.0).contains(&value) => value.to_string(),
+            (MinerAnswer::Option(option), None) => {
+                log!("Invalid option: {}", option);
+                return RevealMinerResult::Fail;
+            }
+            (MinerAnswer::Value(value), Some(_)) => {
+                log!("Value out of bounds: {}", value);
+                return RevealMinerResult::Fail;
+            }
+            (MinerAnswer::Option(_), Some(_)) => {
+                log!("Request expects a numeric answer");
+                return RevealMinerResult::Fail;
+            }
+            (MinerAnswer::Value(_), None) => {
+                log!("Request expects an option");
+                return RevealMinerResult::Fail;
+            }
+        };
+
+        let concatenated_answer = format!("{}{}{}{}", request_id, miner, hashed_answer, hashed_message);
         let hash_value = env::keccak256(concatenated_answer.as_bytes());
         let answer_to_verify = hex::encode(hash_value);
 
@@ -364,13 +613,44 @@ impl Contract {
             return RevealMinerResult::Fail;
         }
 
-        save_proposal.answer = answer;
         save_proposal.is_revealed = true;
 
+        let (message, payload) = match message {
+            MinerMessage::Inline(message) => (Some(message), None),
+            MinerMessage::Payload(payload) => (None, Some(payload)),
+        };
+
+        // @dev The answer of the reveal_miner event was a bool and its message was required before multiple choice,
+        // so the event has a new major version
+        let (version, event) = match answer {
+            MinerAnswer::Option(option) => {
+                save_proposal.answer = option;
+                let event = EventLogVariant::RevealMiner(vec![RevealMinerLog {
+                    request_id: request_id.clone(),
+                    answer: option,
+                    message,
+                    payload,
+                }]);
+                ("2.0.0", event)
+            }
+            MinerAnswer::Value(value) => {
+                complete_request.miner_values.insert(miner.clone(), I128(value));
+                let event = EventLogVariant::RevealMinerValue(vec![RevealMinerValueLog {
+                    request_id: request_id.clone(),
+                    value: I128(value),
+                    message,
+                    payload,
+                }]);
+                ("1.0.0", event)
+            }
+        };
+
+        Self::remove_pending_commit(&mut self.miner_commits, &miner, &request_id);
+
         let reveal_miner_log = EventLog {
             standard: "emip001".to_string(),
-            version: "1.0.0".to_string(),
-            event: EventLogVariant::RevealMiner(vec![RevealMinerLog { request_id, answer, message }]),
+            version: version.to_string(),
+            event,
         };
 
         env::log_str(&reveal_miner_log.to_string());
@@ -379,6 +659,8 @@ impl Contract {
     }
 
     pub fn reveal_by_validator(&mut self, request_id: String, answer: Vec<AccountId>, message: String) -> RevealValidatorResult {
+        self.assert_not_paused(PausableFeature::Reveal);
+
         let validator = env::predecessor_account_id();
 
         if !self.is_validator_registered(validator.clone()) {
@@ -386,6 +668,9 @@ impl Contract {
             return RevealValidatorResult::Fail;
         }
 
+        let stake = *self.validators.get(&validator).unwrap_or_else(|| panic!("Stake not found"));
+        let vote_weight = Self::vote_weight(stake, self.config.vote_weighting, self.config.vote_weight_cap);
+
         //@dev verify that the answer vector have 10 elements
         if answer.len() != 10 {
             log!("Invalid answer");
@@ -414,12 +699,10 @@ impl Contract {
             return RevealValidatorResult::Fail;
         }
 
-        let complete_request = self
-            .get_request_by_id_mut(request_id.clone())
-            .map_or_else(|| panic!("Request not found"), |request| request);
+        let complete_request = self.get_request_by_id_mut(request_id.clone()).unwrap_or_else(|| panic!("Request not found"));
 
         assert_eq!(
-            Self::get_stage(complete_request.start_time),
+            Self::get_stage(complete_request),
             RequestState::RevealValidators,
             "Not at RevealValidators stage"
         );
@@ -427,7 +710,7 @@ impl Contract {
         let save_proposal = complete_request
             .validators_proposals
             .get_mut(&validator)
-            .map_or_else(|| panic!("proposal not found"), |proposal| proposal);
+            .unwrap_or_else(|| panic!("proposal not found"));
 
         if save_proposal.is_revealed {
             log!("Proposal already revealed");
@@ -444,10 +727,7 @@ impl Contract {
 
         //@dev verify that the commit answer by miner was revealed
         for accounts in answer.clone() {
-            let miner_proposal = complete_request
-                .miners_proposals
-                .get(&accounts)
-                .map_or_else(|| panic!("proposal not found"), |proposal| proposal);
+            let miner_proposal = complete_request.miners_proposals.get(&accounts).unwrap_or_else(|| panic!("proposal not found"));
 
             if !miner_proposal.is_revealed {
                 log!("Commit by miner not revealed: {}", accounts);
@@ -475,45 +755,30 @@ impl Contract {
         }
 
         save_proposal.is_revealed = true;
-        let answer_for_log = answer.clone();
 
-        for addresses in answer {
-            save_proposal.miner_addresses.push(addresses.clone());
+        save_proposal.miner_addresses.clone_from(&answer);
 
-            //@dev Find the miner votes and add 1
-            if complete_request.votes_for_miners.contains_key(&addresses) {
-                match complete_request.votes_for_miners.get(&addresses) {
-                    Some(num_votes) => complete_request.votes_for_miners.insert(addresses, *num_votes + 1),
-                    None => panic!("miner not found"),
-                };
-            } else {
-                complete_request.votes_for_miners.insert(addresses.clone(), 1);
-                complete_request.miner_keys.push(addresses);
-            }
-        }
+        //@dev Every ranked miner gets the points of its position times the weight of the validator
+        Self::add_votes(complete_request, &validator, &answer, vote_weight);
+
+        Self::remove_pending_commit(&mut self.validator_commits, &validator, &request_id);
 
         let reveal_validator_log = EventLog {
             standard: "emip001".to_string(),
             version: "1.0.0".to_string(),
-            event: EventLogVariant::RevealValidator(vec![RevealValidatorLog {
-                request_id,
-                answer: answer_for_log,
-                message,
-            }]),
+            event: EventLogVariant::RevealValidator(vec![RevealValidatorLog { request_id, answer, message }]),
         };
 
         env::log_str(&reveal_validator_log.to_string());
         RevealValidatorResult::Success
     }
 
-    pub fn votes_for_miner(&mut self, request_id: String, miner_id: AccountId) {
-        if self.get_request_by_id_mut(request_id.clone()).is_none() {
+    /// Logs the votes of a miner. Kept for compatibility, use `get_votes_for_miner` instead.
+    pub fn votes_for_miner(&self, request_id: String, miner_id: AccountId) {
+        let Some(complete_request) = self.requests.get(&request_id) else {
             log!("Request is not registered: {}", request_id);
-        }
-
-        let complete_request = self
-            .get_request_by_id_mut(request_id)
-            .map_or_else(|| panic!("Request not found"), |request| request);
+            panic!("Request not found");
+        };
 
         match complete_request.votes_for_miners.get(&miner_id) {
             Some(votes) => log!("{} have {} votes", miner_id, *votes),
@@ -521,40 +786,39 @@ impl Contract {
         };
     }
 
-    pub fn get_top_10_voters(&mut self, request_id: String) -> Vec<(AccountId, i32)> {
-        if self.get_request_by_id_mut(request_id.clone()).is_none() {
+    pub fn finalize_request(&mut self, request_id: Hash) -> FinalizeResult {
+        let Some(complete_request) = self.requests.get_mut(&request_id) else {
             log!("Request is not registered: {}", request_id);
-        }
-
-        let complete_request = self
-            .get_request_by_id_mut(request_id.clone())
-            .map_or_else(|| panic!("Request not found"), |request| request);
-
-        assert_eq!(Self::get_stage(complete_request.start_time), RequestState::Ended, "Not stage ended");
+            return FinalizeResult::Fail;
+        };
 
-        let mut vote_result = Vec::new();
+        assert_eq!(Self::get_stage(complete_request), RequestState::Ended, "Not stage ended");
 
-        for miner_keys in complete_request.miner_keys.iter() {
-            if let Some(votes) = complete_request.votes_for_miners.get(miner_keys) {
-                vote_result.push((miner_keys.clone(), *votes));
-            }
+        // @dev The tally runs only once, later calls read the stored outcome
+        if complete_request.is_finalized {
+            log!("Request already finalized: {}", request_id);
+            return FinalizeResult::AlreadyFinalized;
         }
 
-        vote_result.sort_by(|a, b| b.1.cmp(&a.1));
-
-        let top_ten: Vec<_> = vote_result.iter().take(10).cloned().collect();
+        let top_ten = Self::compute_top_ten(complete_request);
         complete_request.top_ten.clone_from(&top_ten);
+        complete_request.is_finalized = true;
 
         let top_ten_log = EventLog {
             standard: "emip001".to_string(),
             version: "1.0.0".to_string(),
             event: EventLogVariant::ToptenMiners(vec![ToptenMinersLog {
-                request_id,
-                topten: top_ten.clone(),
+                request_id: request_id.clone(),
+                topten: top_ten,
             }]),
         };
         env::log_str(&top_ten_log.to_string());
-        top_ten
+
+        self.finalize_decision(&request_id);
+        self.send_decision(&request_id);
+        self.distribute_rewards(&request_id);
+
+        FinalizeResult::Success
     }
 }
 
@@ -589,13 +853,13 @@ mod test {
         contract.register_protocol("Governance decision".to_string(), modules);
 
         let message = "Should we add this new NFT to our protocol?";
-        let result_1 = contract.request_governance_decision(message.to_string());
-        assert_eq!(result_1, RegisterRequestResult::Success);
+        let result_1 = contract.request_governance_decision(message.to_string(), None);
+        assert_eq!(
+            result_1,
+            RegisterRequestResult::Success("1b7b4a1997b364a89227157bb5e816f311616fdbf6206195c68a7c95bf1c6f43".to_string())
+        );
 
-        let sender_account = env::predecessor_account_id();
-        let concatenated_answer = format!("{}{}", sender_account, message);
-        let request_id = env::keccak256(concatenated_answer.as_bytes());
-        let request_id_hex = hex::encode(request_id);
+        let request_id_hex = Contract::derive_request_id(&env::predecessor_account_id(), message, 0);
 
         assert!(contract.get_request_by_id_mut(request_id_hex).is_some());
 
@@ -608,7 +872,7 @@ mod test {
 
         assert_eq!(
             logs[1],
-            r#"EVENT_JSON:{"standard":"emip001","version":"1.0.0","event":"register_request","data":[{"request_id":"73ead60176d724e462dbfa8d49506177bb13bec748cf5af5019b6d1da63e204b"}]}"#
+            r#"EVENT_JSON:{"standard":"emip001","version":"1.0.0","event":"register_request","data":[{"request_id":"1b7b4a1997b364a89227157bb5e816f311616fdbf6206195c68a7c95bf1c6f43"}]}"#
         );
     }
 
@@ -623,13 +887,13 @@ mod test {
         contract.register_protocol("Governance decision".to_string(), modules);
 
         let message = "Should we add this new NFT to our protocol?";
-        let result_1 = contract.request_governance_decision(message.to_string());
-        assert_eq!(result_1, RegisterRequestResult::Success);
+        let result_1 = contract.request_governance_decision(message.to_string(), None);
+        assert_eq!(
+            result_1,
+            RegisterRequestResult::Success("1b7b4a1997b364a89227157bb5e816f311616fdbf6206195c68a7c95bf1c6f43".to_string())
+        );
 
-        let sender_account = env::predecessor_account_id();
-        let concatenated_answer = format!("{}{}", sender_account, message);
-        let request_id = env::keccak256(concatenated_answer.as_bytes());
-        let request_id_hex = hex::encode(request_id);
+        let request_id_hex = Contract::derive_request_id(&env::predecessor_account_id(), message, 0);
 
         assert!(contract.get_request_by_id_mut(request_id_hex).is_some());
 
@@ -642,7 +906,7 @@ mod test {
 
         assert_eq!(
             logs[1],
-            r#"EVENT_JSON:{"standard":"emip001","version":"1.0.0","event":"register_request","data":[{"request_id":"73ead60176d724e462dbfa8d49506177bb13bec748cf5af5019b6d1da63e204b"}]}"#
+            r#"EVENT_JSON:{"standard":"emip001","version":"1.0.0","event":"register_request","data":[{"request_id":"1b7b4a1997b364a89227157bb5e816f311616fdbf6206195c68a7c95bf1c6f43"}]}"#
         );
 
         let context = get_context("account2.near".parse().unwrap(), 100000000, NearToken::from_yoctonear(10u128.pow(25)));
@@ -652,13 +916,10 @@ mod test {
         contract.register_protocol("Governance decision for ethereum".to_string(), modules);
 
         let message_2 = "Should we add this to our protocol?";
-        let result_2 = contract.request_governance_decision(message_2.to_string());
-        assert_eq!(result_2, RegisterRequestResult::Success);
+        let result_2 = contract.request_governance_decision(message_2.to_string(), None);
 
-        let sender_account_2 = env::predecessor_account_id();
-        let concatenated_answer_2 = format!("{}{}", sender_account_2, message_2);
-        let request_id_2 = env::keccak256(concatenated_answer_2.as_bytes());
-        let request_id_hex_2 = hex::encode(request_id_2);
+        let request_id_hex_2 = Contract::derive_request_id(&env::predecessor_account_id(), message_2, 0);
+        assert_eq!(result_2, RegisterRequestResult::Success(request_id_hex_2.clone()));
 
         assert!(contract.get_request_by_id_mut(request_id_hex_2).is_some());
         let logs = get_logs();
@@ -669,7 +930,7 @@ mod test {
         );
         assert_eq!(
             logs[1],
-            r#"EVENT_JSON:{"standard":"emip001","version":"1.0.0","event":"register_request","data":[{"request_id":"c4b35bc95d323446f6f800e7639457cddc34c7f768772e4871adf2dd34f89ed8"}]}"#
+            r#"EVENT_JSON:{"standard":"emip001","version":"1.0.0","event":"register_request","data":[{"request_id":"e3c2d87255733faa896cacf7b06f7b01b4054cfc462ccfcb7614051b22d03434"}]}"#
         );
     }
 
@@ -683,7 +944,7 @@ mod test {
 
         let message = "Should we add this new NFT to our protocol?";
 
-        contract.request_governance_decision(message.to_string());
+        contract.request_governance_decision(message.to_string(), None);
     }
 
     #[test]
@@ -697,9 +958,9 @@ mod test {
         contract.register_protocol("Governance decision for ethereum".to_string(), modules);
 
         let message = "Should we add this new NFT to our protocol?";
-        contract.request_governance_decision(message.to_string());
+        contract.request_governance_decision(message.to_string(), None);
 
-        let request_id = "73ead60176d724e462dbfa8d49506177bb13bec748cf5af5019b6d1da63e204b";
+        let request_id = "1b7b4a1997b364a89227157bb5e816f311616fdbf6206195c68a7c95bf1c6f43";
         assert!(contract.get_request_by_id_mut(request_id.to_string()).is_some());
     }
 
@@ -712,9 +973,9 @@ mod test {
         let mut contract = Contract::new();
 
         let message = "Should we add this new NFT to our protocol?";
-        contract.request_governance_decision(message.to_string());
+        contract.request_governance_decision(message.to_string(), None);
 
-        let request_id = "73ead60176d724e462dbfa8d49506177bb13bec748cf5af5019b6d1da63e204b";
+        let request_id = "1b7b4a1997b364a89227157bb5e816f311616fdbf6206195c68a7c95bf1c6f43";
         assert!(contract.get_request_by_id_mut(request_id.to_string()).is_some());
     }
 
@@ -725,4 +986,22 @@ mod test {
 
         assert!(contract.get_request_by_id_mut(request_id.to_string()).is_none());
     }
+
+    #[test]
+    fn test_vote_weight() {
+        let stake = NearToken::from_near(160);
+
+        assert_eq!(Contract::vote_weight(stake, VoteWeighting::Linear, VOTE_WEIGHT_CAP), 16);
+        assert_eq!(Contract::vote_weight(stake, VoteWeighting::SquareRoot, VOTE_WEIGHT_CAP), 4);
+        assert_eq!(Contract::vote_weight(stake, VoteWeighting::Capped, VOTE_WEIGHT_CAP), VOTE_WEIGHT_CAP);
+    }
+
+    #[test]
+    fn test_vote_weight_is_at_least_one() {
+        let stake = NearToken::from_near(1);
+
+        assert_eq!(Contract::vote_weight(stake, VoteWeighting::Linear, VOTE_WEIGHT_CAP), 1);
+        assert_eq!(Contract::vote_weight(stake, VoteWeighting::SquareRoot, VOTE_WEIGHT_CAP), 1);
+        assert_eq!(Contract::vote_weight(stake, VoteWeighting::Capped, VOTE_WEIGHT_CAP), 1);
+    }
 }
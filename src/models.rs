@@ -1,4 +1,5 @@
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::I128;
 use near_sdk::serde::{Deserialize, Serialize};
 use near_sdk::store::LookupMap;
 use near_sdk::{AccountId, NearToken};
@@ -32,8 +33,7 @@ pub enum RegisterValidatorResult {
 #[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
 #[serde(crate = "near_sdk::serde")]
 pub enum RegisterRequestResult {
-    Success,
-    AlreadyRegistered,
+    Success(Hash),
 }
 
 #[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
@@ -64,11 +64,87 @@ pub enum RevealValidatorResult {
     Fail,
 }
 
+#[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum IncreaseStakeResult {
+    Success,
+    NotRegistered,
+}
+
+#[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum UnstakeResult {
+    Success,
+    NotRegistered,
+    InvalidAmount,
+}
+
+#[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum WithdrawResult {
+    Success,
+    NothingToWithdraw,
+    StillUnbonding,
+    PendingCommits,
+}
+
+#[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum SettleResult {
+    Success,
+    AlreadySettled,
+    Fail,
+}
+
+#[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum ClaimRewardsResult {
+    Success,
+    NothingToClaim,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum SlashReason {
+    NotRevealed,
+    OutlierRanking,
+}
+
+/// Entry points that can be paused independently.
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum PausableFeature {
+    Registration,
+    Requests,
+    Commit,
+    Reveal,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PauseState {
+    pub registration: bool,
+    pub requests: bool,
+    pub commit: bool,
+    pub reveal: bool,
+}
+
+/// Administrative roles. The owner is a single account that is transferred in two steps,
+/// the rest of the roles are granted to any number of accounts.
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Role {
+    Owner,
+    Admin,
+    Pauser,
+    Treasurer,
+}
+
 #[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone)]
 #[serde(crate = "near_sdk::serde")]
 pub struct MinerProposal {
     pub proposal_hash: Hash,
-    pub answer: bool,
+    pub answer: u32,
     pub is_revealed: bool,
 }
 
@@ -83,13 +159,83 @@ pub enum RequestState {
     Ended,
 }
 
+#[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum FinalizeResult {
+    Success,
+    AlreadyFinalized,
+    Fail,
+}
+
+#[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum UpdateScheduleResult {
+    Success,
+    NotRegisteredProtocol,
+    InvalidSchedule,
+}
+
+#[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum UpdateConfigResult {
+    Success,
+    InvalidConfig,
+}
+
+#[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum UpdateRoleResult {
+    Success,
+    AlreadyGranted,
+    NotGranted,
+}
+
+#[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum WithdrawTreasuryResult {
+    Success,
+    InvalidAmount,
+}
+
+#[derive(Debug, Serialize, Deserialize, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum RetryCallbackResult {
+    Success,
+    NotFailed,
+    Fail,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum DecisionCallbackStatus {
+    Pending,
+    Delivered,
+    Failed,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum StageFilter {
+    Open,
+    InProgress,
+    Ended,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum VoteWeighting {
+    Linear,
+    SquareRoot,
+    Capped,
+}
+
 #[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 #[serde(crate = "near_sdk::serde")]
 pub enum Module {
     TextPrompting,
     ObjectRecognition,
 }
-#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug)]
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone)]
 #[serde(crate = "near_sdk::serde")]
 pub struct ValidatorProposal {
     pub proposal_hash: Hash,
@@ -97,6 +243,102 @@ pub struct ValidatorProposal {
     pub miner_addresses: Vec<AccountId>,
 }
 
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct UnbondingStake {
+    pub amount: NearToken,
+    pub unlock_time: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct VoteScore {
+    pub validator: AccountId,
+    pub position: u32,
+    pub points: u32,
+    pub weight: u32,
+    pub score: u32,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Decision {
+    pub option: Option<u32>, // Winning option, `None` when no option exceeds the decision threshold of the config
+    pub votes: Vec<u32>,     // Revealed answers of the top ten for every option, empty for numeric requests
+    pub value: Option<I128>, // Aggregated value of a numeric request, `None` when no value was revealed
+}
+
+/// How the revealed values of a numeric request are combined into its decision.
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Aggregation {
+    Median,
+    TrimmedMean,
+}
+
+/// Answer of a request asking for a number. Values are fixed-point integers with `decimals` decimals
+/// between `min` and `max`, both included.
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct NumericAnswer {
+    pub min: I128,
+    pub max: I128,
+    pub decimals: u8,
+    pub aggregation: Aggregation,
+}
+
+/// Answer revealed by a miner, hashed with its decimal representation.
+#[derive(Debug, Clone, Copy)]
+pub enum MinerAnswer {
+    Option(u32),
+    Value(i128),
+}
+
+/// Reference to an answer stored off chain. `content_hash` is the hex encoded hash of the content behind `uri`.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AnswerPayload {
+    pub content_hash: Hash,
+    pub uri: String,
+}
+
+/// Reasoning revealed with the answer of a miner, either inline or as a reference to an off-chain payload.
+#[derive(Debug, Clone)]
+pub enum MinerMessage {
+    Inline(String),
+    Payload(AnswerPayload),
+}
+
+/// Economic parameters of the contract that the owner can update without a redeploy.
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Config {
+    pub min_miner_stake: NearToken,
+    pub min_validator_stake: NearToken,
+    pub protocol_registration_fee: NearToken,
+    pub unbonding_period: u64,
+    pub reward_per_request: NearToken,
+    pub miner_reward_percentage: u128,
+    pub bounty_min_revealed_validators: u32,
+    pub miner_slash_percentage: u128,
+    pub validator_slash_percentage: u128,
+    pub validator_deviation_threshold: u32,
+    pub vote_weighting: VoteWeighting,
+    pub vote_weight_cap: u32,
+    pub decision_threshold_percentage: u32,
+    pub trimmed_percentage: u32,
+}
+
+/// Duration in nanoseconds of every stage of a request.
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PhaseSchedule {
+    pub commit_miners: u64,
+    pub reveal_miners: u64,
+    pub commit_validators: u64,
+    pub reveal_validators: u64,
+}
+
 #[derive(BorshDeserialize, BorshSerialize, Debug)]
 pub struct Request {
     pub sender: AccountId,
@@ -104,9 +346,92 @@ pub struct Request {
     pub start_time: u64,
     pub miners_proposals: LookupMap<AccountId, MinerProposal>,
     pub validators_proposals: LookupMap<AccountId, ValidatorProposal>,
-    pub votes_for_miners: LookupMap<AccountId, i32>,
+    pub votes_for_miners: LookupMap<AccountId, u32>,
+    pub score_breakdown: LookupMap<AccountId, Vec<VoteScore>>,
     pub miner_keys: Vec<AccountId>,
-    pub top_ten: Vec<(AccountId, i32)>,
+    pub top_ten: Vec<(AccountId, u32)>,
+    pub committed_miners: Vec<AccountId>,
+    pub committed_validators: Vec<AccountId>,
+    pub miners_settled: bool,
+    pub validators_settled: bool,
+    pub is_finalized: bool,
+    pub bounty: NearToken,
+    pub decision: Option<Decision>,
+    pub callback_status: Option<DecisionCallbackStatus>,
+    pub schedule: PhaseSchedule,
+    pub options: Vec<String>,
+    pub numeric: Option<NumericAnswer>,
+    pub miner_values: LookupMap<AccountId, I128>,
+}
+
+/// Layout of the requests of the baseline contract, which stored them without a version.
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub struct RequestV0 {
+    pub sender: AccountId,
+    pub request_id: String,
+    pub start_time: u64,
+    pub miners_proposals: LookupMap<AccountId, MinerProposal>,
+    pub validators_proposals: LookupMap<AccountId, ValidatorProposal>,
+    pub votes_for_miners: LookupMap<AccountId, u32>, // Stored as i32, which has the same encoding for counts that are never negative
+    pub miner_keys: Vec<AccountId>,
+    pub top_ten: Vec<(AccountId, u32)>,
+}
+
+/// Versions of the stored requests. Baseline requests, stored without a version, are converted to the current one when they are read.
+#[derive(BorshSerialize, Debug)]
+pub enum VersionedRequest {
+    V1(Request),
+}
+
+/// Versions of the stored protocols. Older versions are converted to the current one when they are read.
+#[derive(BorshSerialize, Debug)]
+pub enum VersionedProtocol {
+    V1(Protocol),
+}
+
+/// Layout version of the contract state, stored as its last field.
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum StateVersion {
+    V1,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ParticipantView {
+    pub account: AccountId,
+    pub stake: NearToken,
+    pub registered_at: u64,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RequestView {
+    pub request_id: Hash,
+    pub sender: AccountId,
+    pub start_time: u64,
+    pub stage: RequestState,
+    pub commit_miners_deadline: u64,
+    pub reveal_miners_deadline: u64,
+    pub commit_validators_deadline: u64,
+    pub reveal_validators_deadline: u64,
+    pub miners_committed: u32,
+    pub miners_revealed: u32,
+    pub validators_committed: u32,
+    pub validators_revealed: u32,
+    pub bounty: NearToken,
+    pub options: Vec<String>,
+    pub numeric: Option<NumericAnswer>,
+    pub top_ten: Option<Vec<(AccountId, u32)>>,
+    pub decision: Option<Decision>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RequestResults {
+    pub request_id: Hash,
+    pub top_ten: Vec<(AccountId, u32)>,
+    pub decision: Option<Decision>,
 }
 
 #[derive(BorshDeserialize, BorshSerialize, Debug)]
@@ -115,4 +440,14 @@ pub struct Protocol {
     pub culture: String,
     pub modules: Vec<Module>,
     pub registration_fee: NearToken,
+    pub schedule: PhaseSchedule,
+}
+
+/// Layout of the protocols of the baseline contract, which stored them without a version.
+#[derive(BorshDeserialize, BorshSerialize, Debug)]
+pub struct ProtocolV0 {
+    pub account: AccountId,
+    pub culture: String,
+    pub modules: Vec<Module>,
+    pub registration_fee: NearToken,
 }
@@ -0,0 +1,64 @@
+use near_sdk::{env, log, near_bindgen, require};
+
+use crate::*;
+
+#[near_bindgen]
+impl Contract {
+    pub fn pause(&mut self, feature: PausableFeature) {
+        self.assert_any_role(&[Role::Owner, Role::Pauser]);
+
+        *self.pause_flag(feature) = true;
+
+        let paused_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "1.0.0".to_string(),
+            event: EventLogVariant::Paused(vec![PausedLog {
+                feature,
+                account: env::predecessor_account_id(),
+            }]),
+        };
+
+        log!(&paused_log.to_string());
+    }
+
+    pub fn unpause(&mut self, feature: PausableFeature) {
+        self.assert_any_role(&[Role::Owner, Role::Pauser]);
+
+        *self.pause_flag(feature) = false;
+
+        let unpaused_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "1.0.0".to_string(),
+            event: EventLogVariant::Unpaused(vec![UnpausedLog {
+                feature,
+                account: env::predecessor_account_id(),
+            }]),
+        };
+
+        log!(&unpaused_log.to_string());
+    }
+
+    pub fn get_pause_state(&self) -> PauseState {
+        self.paused.clone()
+    }
+}
+
+impl Contract {
+    pub(crate) fn assert_not_paused(&self, feature: PausableFeature) {
+        match feature {
+            PausableFeature::Registration => require!(!self.paused.registration, "Registration is paused"),
+            PausableFeature::Requests => require!(!self.paused.requests, "Request creation is paused"),
+            PausableFeature::Commit => require!(!self.paused.commit, "Commits are paused"),
+            PausableFeature::Reveal => require!(!self.paused.reveal, "Reveals are paused"),
+        }
+    }
+
+    const fn pause_flag(&mut self, feature: PausableFeature) -> &mut bool {
+        match feature {
+            PausableFeature::Registration => &mut self.paused.registration,
+            PausableFeature::Requests => &mut self.paused.requests,
+            PausableFeature::Commit => &mut self.paused.commit,
+            PausableFeature::Reveal => &mut self.paused.reveal,
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::store::{LookupMap, Vector};
+use near_sdk::AccountId;
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+struct RegistryEntry {
+    index: u32,
+    registered_at: u64,
+}
+
+/// Iterable set of registered accounts with their registration time.
+/// Removing an account moves the last account into its slot.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Registry {
+    accounts: Vector<AccountId>,
+    entries: LookupMap<AccountId, RegistryEntry>,
+}
+
+impl Registry {
+    pub fn new(prefix: &[u8]) -> Self {
+        Self {
+            accounts: Vector::new([prefix, b"_accounts"].concat()),
+            entries: LookupMap::new([prefix, b"_entries"].concat()),
+        }
+    }
+
+    /// Adds `account` unless it is already registered, and returns whether it was added.
+    pub fn insert(&mut self, account: AccountId, registered_at: u64) -> bool {
+        if self.entries.contains_key(&account) {
+            return false;
+        }
+
+        self.entries.insert(
+            account.clone(),
+            RegistryEntry {
+                index: self.accounts.len(),
+                registered_at,
+            },
+        );
+        self.accounts.push(account);
+
+        true
+    }
+
+    pub fn remove(&mut self, account: &AccountId) {
+        let Some(entry) = self.entries.remove(account) else {
+            return;
+        };
+
+        self.accounts.swap_remove(entry.index);
+
+        if let Some(moved) = self.accounts.get(entry.index).cloned() {
+            if let Some(moved_entry) = self.entries.get_mut(&moved) {
+                moved_entry.index = entry.index;
+            }
+        }
+    }
+
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn len(&self) -> u32 {
+        self.accounts.len()
+    }
+
+    /// Accounts from `from_index` on, at most `limit` of them, with their registration time.
+    pub fn page(&self, from_index: u32, limit: u32) -> Vec<(AccountId, u64)> {
+        let end = from_index.saturating_add(limit).min(self.accounts.len());
+
+        (from_index..end)
+            .filter_map(|index| self.accounts.get(index))
+            .filter_map(|account| self.entries.get(account).map(|entry| (account.clone(), entry.registered_at)))
+            .collect()
+    }
+}
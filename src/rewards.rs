@@ -0,0 +1,139 @@
+use near_sdk::{env, log, near_bindgen, AccountId, NearToken, Promise};
+
+use crate::*;
+
+#[near_bindgen]
+impl Contract {
+    pub fn claim_rewards(&mut self) -> ClaimRewardsResult {
+        let account = env::predecessor_account_id();
+
+        let Some(amount) = self.rewards.remove(&account) else {
+            log!("No rewards to claim for: {}", account);
+            return ClaimRewardsResult::NothingToClaim;
+        };
+
+        Promise::new(account.clone()).transfer(amount);
+
+        let claim_rewards_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "1.0.0".to_string(),
+            event: EventLogVariant::ClaimRewards(vec![ClaimRewardsLog { account, amount }]),
+        };
+
+        log!(&claim_rewards_log.to_string());
+
+        ClaimRewardsResult::Success
+    }
+
+    pub fn get_pending_rewards(&self, account: AccountId) -> NearToken {
+        self.rewards.get(&account).copied().unwrap_or(NearToken::from_yoctonear(0))
+    }
+}
+
+impl Contract {
+    /// Splits the reward of a finalized request between the miners in its top ten
+    /// and the validators that revealed a ranking close enough to it. The reward is taken from the treasury
+    /// and increased by the bounty of the request, which is refunded when participation is too low.
+    pub(crate) fn distribute_rewards(&mut self, request_id: &Hash) {
+        let deviation_threshold = self.config.validator_deviation_threshold as usize;
+        let request = self.requests.get(request_id).unwrap_or_else(|| panic!("Request not found"));
+
+        let miners: Vec<AccountId> = request.top_ten.iter().map(|(miner, _)| miner.clone()).collect();
+        let revealed_validators = request
+            .committed_validators
+            .iter()
+            .filter(|validator| request.validators_proposals.get(*validator).is_some_and(|proposal| proposal.is_revealed))
+            .count();
+
+        // @dev Outliers are slashed by settle_validators, so they get no share of the reward
+        let validators: Vec<AccountId> = request
+            .committed_validators
+            .iter()
+            .filter(|validator| {
+                request
+                    .validators_proposals
+                    .get(*validator)
+                    .is_some_and(|proposal| proposal.is_revealed && Self::ranking_deviation(&proposal.miner_addresses, &miners) <= deviation_threshold)
+            })
+            .cloned()
+            .collect();
+
+        let base_reward = if self.treasury < self.config.reward_per_request {
+            self.treasury
+        } else {
+            self.config.reward_per_request
+        };
+
+        // @dev The bounty is added to the reward only if enough validators took part in the request
+        let mut bounty = request.bounty;
+
+        if !bounty.is_zero() && revealed_validators < self.config.bounty_min_revealed_validators as usize {
+            Promise::new(request.sender.clone()).transfer(bounty);
+
+            let refund_bounty_log = EventLog {
+                standard: "emip001".to_string(),
+                version: "1.0.0".to_string(),
+                event: EventLogVariant::RefundBounty(vec![RefundBountyLog {
+                    request_id: request_id.clone(),
+                    account: request.sender.clone(),
+                    amount: bounty,
+                }]),
+            };
+
+            log!(&refund_bounty_log.to_string());
+
+            bounty = NearToken::from_yoctonear(0);
+        }
+
+        // @dev The escrowed bounty joins the treasury so that any undistributed remainder stays there
+        self.treasury = self.treasury.saturating_add(bounty);
+
+        let pool = base_reward.saturating_add(bounty);
+        let miners_pool = pool.saturating_mul(self.config.miner_reward_percentage).saturating_div(100);
+        let validators_pool = pool.saturating_sub(miners_pool);
+
+        let miner_reward = Self::credit_rewards(&mut self.rewards, &miners, miners_pool);
+        let validator_reward = Self::credit_rewards(&mut self.rewards, &validators, validators_pool);
+
+        // @dev Only the distributed amount leaves the treasury, the remainder of the divisions stays
+        let distributed = miner_reward
+            .saturating_mul(miners.len() as u128)
+            .saturating_add(validator_reward.saturating_mul(validators.len() as u128));
+        self.treasury = self.treasury.saturating_sub(distributed);
+
+        if distributed.is_zero() {
+            return;
+        }
+
+        let distribute_rewards_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "1.0.0".to_string(),
+            event: EventLogVariant::DistributeRewards(vec![DistributeRewardsLog {
+                request_id: request_id.clone(),
+                miners,
+                miner_reward,
+                validators,
+                validator_reward,
+                bounty,
+            }]),
+        };
+
+        log!(&distribute_rewards_log.to_string());
+    }
+
+    /// Credits an equal share of `pool` to every account and returns the share.
+    fn credit_rewards(rewards: &mut LookupMap<AccountId, NearToken>, accounts: &[AccountId], pool: NearToken) -> NearToken {
+        if accounts.is_empty() {
+            return NearToken::from_yoctonear(0);
+        }
+
+        let share = pool.saturating_div(accounts.len() as u128);
+
+        for account in accounts {
+            let pending = rewards.get(account).copied().unwrap_or(NearToken::from_yoctonear(0));
+            rewards.insert(account.clone(), pending.saturating_add(share));
+        }
+
+        share
+    }
+}
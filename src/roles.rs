@@ -0,0 +1,145 @@
+use near_sdk::{env, log, near_bindgen, require, AccountId};
+
+use crate::*;
+
+#[near_bindgen]
+impl Contract {
+    /// Grants `role` to `account`. The owner manages admins, and both the owner and the admins
+    /// manage pausers and treasurers.
+    pub fn grant_role(&mut self, account: AccountId, role: Role) -> UpdateRoleResult {
+        self.assert_can_manage(role);
+
+        let roles = self.roles.entry(account.clone()).or_default();
+
+        if roles.contains(&role) {
+            log!("Account already has the role: {}", account);
+            return UpdateRoleResult::AlreadyGranted;
+        }
+
+        roles.push(role);
+
+        let role_granted_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "1.0.0".to_string(),
+            event: EventLogVariant::RoleGranted(vec![RoleGrantedLog {
+                account,
+                role,
+                granted_by: env::predecessor_account_id(),
+            }]),
+        };
+
+        log!(&role_granted_log.to_string());
+
+        UpdateRoleResult::Success
+    }
+
+    pub fn revoke_role(&mut self, account: AccountId, role: Role) -> UpdateRoleResult {
+        self.assert_can_manage(role);
+
+        let Some(roles) = self.roles.get_mut(&account).filter(|roles| roles.contains(&role)) else {
+            log!("Account does not have the role: {}", account);
+            return UpdateRoleResult::NotGranted;
+        };
+
+        roles.retain(|granted| *granted != role);
+
+        if roles.is_empty() {
+            self.roles.remove(&account);
+        }
+
+        let role_revoked_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "1.0.0".to_string(),
+            event: EventLogVariant::RoleRevoked(vec![RoleRevokedLog {
+                account,
+                role,
+                revoked_by: env::predecessor_account_id(),
+            }]),
+        };
+
+        log!(&role_revoked_log.to_string());
+
+        UpdateRoleResult::Success
+    }
+
+    /// First step of an ownership transfer. The new owner takes over once it calls `accept_ownership`,
+    /// and calling this method again replaces the pending owner.
+    pub fn transfer_ownership(&mut self, new_owner: AccountId) {
+        self.assert_any_role(&[Role::Owner]);
+
+        self.pending_owner = Some(new_owner.clone());
+
+        let ownership_transfer_started_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "1.0.0".to_string(),
+            event: EventLogVariant::OwnershipTransferStarted(vec![OwnershipTransferStartedLog {
+                owner: self.owner.clone(),
+                pending_owner: new_owner,
+            }]),
+        };
+
+        log!(&ownership_transfer_started_log.to_string());
+    }
+
+    pub fn accept_ownership(&mut self) {
+        let account = env::predecessor_account_id();
+
+        require!(self.pending_owner.as_ref() == Some(&account), "Only the pending owner can accept the ownership");
+
+        let previous_owner = std::mem::replace(&mut self.owner, account.clone());
+        self.pending_owner = None;
+
+        let ownership_transferred_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "1.0.0".to_string(),
+            event: EventLogVariant::OwnershipTransferred(vec![OwnershipTransferredLog {
+                previous_owner,
+                owner: account,
+            }]),
+        };
+
+        log!(&ownership_transferred_log.to_string());
+    }
+
+    pub fn get_owner(&self) -> AccountId {
+        self.owner.clone()
+    }
+
+    pub fn get_pending_owner(&self) -> Option<AccountId> {
+        self.pending_owner.clone()
+    }
+
+    pub fn get_roles(&self, account: AccountId) -> Vec<Role> {
+        let mut roles = if account == self.owner { vec![Role::Owner] } else { Vec::new() };
+        roles.extend(self.roles.get(&account).into_iter().flatten().copied());
+        roles
+    }
+
+    pub fn has_role(&self, account: AccountId, role: Role) -> bool {
+        self.has_any_role(&account, &[role])
+    }
+}
+
+impl Contract {
+    pub(crate) fn assert_any_role(&self, roles: &[Role]) {
+        require!(
+            self.has_any_role(&env::predecessor_account_id(), roles),
+            format!("Only an account with one of the roles {:?} can call this method", roles)
+        );
+    }
+
+    fn has_any_role(&self, account: &AccountId, roles: &[Role]) -> bool {
+        roles.iter().any(|role| match role {
+            Role::Owner => *account == self.owner,
+            _ => self.roles.get(account).is_some_and(|granted| granted.contains(role)),
+        })
+    }
+
+    fn assert_can_manage(&self, role: Role) {
+        match role {
+            Role::Owner => panic!("The owner is changed with transfer_ownership"),
+            Role::Admin => self.assert_any_role(&[Role::Owner]),
+            Role::Pauser | Role::Treasurer => self.assert_any_role(&[Role::Owner, Role::Admin]),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use near_sdk::{env, log, near_bindgen, AccountId};
+
+use crate::*;
+
+#[near_bindgen]
+impl Contract {
+    /// Sets the stage durations of the requests the caller sends from now on. Requests that
+    /// were already created keep the schedule they were created with.
+    pub fn update_phase_schedule(&mut self, schedule: PhaseSchedule) -> UpdateScheduleResult {
+        let account = env::predecessor_account_id();
+
+        let Some(protocol) = self.protocols.get_mut(&account) else {
+            log!("Protocol is not registered: {}", account);
+            return UpdateScheduleResult::NotRegisteredProtocol;
+        };
+
+        if !Self::is_valid_schedule(&schedule) {
+            log!("Phase durations must be between {} and {} nanoseconds", MIN_PHASE_DURATION, MAX_PHASE_DURATION);
+            return UpdateScheduleResult::InvalidSchedule;
+        }
+
+        protocol.schedule = schedule;
+
+        let update_phase_schedule_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "1.0.0".to_string(),
+            event: EventLogVariant::UpdatePhaseSchedule(vec![UpdatePhaseScheduleLog { account, schedule }]),
+        };
+
+        log!(&update_phase_schedule_log.to_string());
+
+        UpdateScheduleResult::Success
+    }
+
+    pub fn get_phase_schedule(&self, account: AccountId) -> Option<PhaseSchedule> {
+        self.protocols.get(&account).map(|protocol| protocol.schedule)
+    }
+}
+
+impl Contract {
+    fn is_valid_schedule(schedule: &PhaseSchedule) -> bool {
+        [
+            schedule.commit_miners,
+            schedule.reveal_miners,
+            schedule.commit_validators,
+            schedule.reveal_validators,
+        ]
+        .iter()
+        .all(|duration| (MIN_PHASE_DURATION..=MAX_PHASE_DURATION).contains(duration))
+    }
+}
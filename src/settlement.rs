@@ -0,0 +1,206 @@
+use near_sdk::store::LookupMap;
+use near_sdk::{env, log, near_bindgen, require, AccountId, NearToken, Promise};
+
+use crate::*;
+
+#[near_bindgen]
+impl Contract {
+    pub fn settle_miners(&mut self, request_id: Hash) -> SettleResult {
+        // @dev Miners can't be slashed for not revealing while reveals are paused
+        self.assert_not_paused(PausableFeature::Reveal);
+
+        let Some(request) = self.requests.get_mut(&request_id) else {
+            log!("Request is not registered: {}", request_id);
+            return SettleResult::Fail;
+        };
+
+        require!(
+            matches!(
+                Self::get_stage(request),
+                RequestState::CommitValidators | RequestState::RevealValidators | RequestState::Ended
+            ),
+            "RevealMiners stage has not ended"
+        );
+
+        if request.miners_settled {
+            log!("Miners already settled for request: {}", request_id);
+            return SettleResult::AlreadySettled;
+        }
+
+        request.miners_settled = true;
+
+        // @dev Miners that committed an answer but never revealed it
+        let unrevealed_miners: Vec<AccountId> = request
+            .committed_miners
+            .iter()
+            .filter(|miner| request.miners_proposals.get(*miner).is_some_and(|proposal| !proposal.is_revealed))
+            .cloned()
+            .collect();
+
+        let mut slash_miner_logs = Vec::new();
+
+        for miner in unrevealed_miners {
+            let amount = Self::slash(&mut self.miners, &mut self.miners_unbonding, &miner, self.config.miner_slash_percentage);
+
+            // @dev Slashed funds are kept in the contract treasury
+            self.treasury = self.treasury.saturating_add(amount);
+
+            Self::remove_pending_commit(&mut self.miner_commits, &miner, &request_id);
+
+            slash_miner_logs.push(SlashMinerLog {
+                request_id: request_id.clone(),
+                miner,
+                amount,
+            });
+        }
+
+        if !slash_miner_logs.is_empty() {
+            let slash_miner_log = EventLog {
+                standard: "emip001".to_string(),
+                version: "1.0.0".to_string(),
+                event: EventLogVariant::SlashMiner(slash_miner_logs),
+            };
+
+            log!(&slash_miner_log.to_string());
+        }
+
+        SettleResult::Success
+    }
+
+    pub fn settle_validators(&mut self, request_id: Hash) -> SettleResult {
+        self.assert_not_paused(PausableFeature::Reveal);
+
+        let deviation_threshold = self.config.validator_deviation_threshold as usize;
+
+        let Some(request) = self.requests.get_mut(&request_id) else {
+            log!("Request is not registered: {}", request_id);
+            return SettleResult::Fail;
+        };
+
+        assert_eq!(Self::get_stage(request), RequestState::Ended, "Not stage ended");
+
+        if request.validators_settled {
+            log!("Validators already settled for request: {}", request_id);
+            return SettleResult::AlreadySettled;
+        }
+
+        request.validators_settled = true;
+
+        let top_ten: Vec<AccountId> = Self::compute_top_ten(request).into_iter().map(|(miner, _)| miner).collect();
+
+        let mut penalized_validators = Vec::new();
+
+        for validator in request.committed_validators.iter() {
+            let Some(proposal) = request.validators_proposals.get(validator) else {
+                continue;
+            };
+
+            if !proposal.is_revealed {
+                penalized_validators.push((validator.clone(), SlashReason::NotRevealed));
+                continue;
+            }
+
+            if Self::ranking_deviation(&proposal.miner_addresses, &top_ten) > deviation_threshold {
+                penalized_validators.push((validator.clone(), SlashReason::OutlierRanking));
+            }
+        }
+
+        let mut slash_validator_logs = Vec::new();
+
+        for (validator, reason) in penalized_validators {
+            let amount = Self::slash(
+                &mut self.validators,
+                &mut self.validators_unbonding,
+                &validator,
+                self.config.validator_slash_percentage,
+            );
+
+            self.treasury = self.treasury.saturating_add(amount);
+
+            Self::remove_pending_commit(&mut self.validator_commits, &validator, &request_id);
+
+            slash_validator_logs.push(SlashValidatorLog {
+                request_id: request_id.clone(),
+                validator,
+                amount,
+                reason,
+            });
+        }
+
+        if !slash_validator_logs.is_empty() {
+            let slash_validator_log = EventLog {
+                standard: "emip001".to_string(),
+                version: "1.0.0".to_string(),
+                event: EventLogVariant::SlashValidator(slash_validator_logs),
+            };
+
+            log!(&slash_validator_log.to_string());
+        }
+
+        SettleResult::Success
+    }
+
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn get_treasury_balance(&self) -> NearToken {
+        self.treasury
+    }
+
+    /// Transfers `amount` of the treasury to the calling treasurer.
+    pub fn withdraw_treasury(&mut self, amount: NearToken) -> WithdrawTreasuryResult {
+        self.assert_any_role(&[Role::Treasurer]);
+
+        let treasurer = env::predecessor_account_id();
+
+        if amount.is_zero() || amount > self.treasury {
+            log!("Invalid amount to withdraw from the treasury: {}", amount);
+            return WithdrawTreasuryResult::InvalidAmount;
+        }
+
+        self.treasury = self.treasury.saturating_sub(amount);
+
+        Promise::new(treasurer.clone()).transfer(amount);
+
+        let withdraw_treasury_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "1.0.0".to_string(),
+            event: EventLogVariant::WithdrawTreasury(vec![WithdrawTreasuryLog { account: treasurer, amount }]),
+        };
+
+        log!(&withdraw_treasury_log.to_string());
+
+        WithdrawTreasuryResult::Success
+    }
+}
+
+impl Contract {
+    /// Number of miners ranked in `ranking` that didn't make it into `top_ten`. Validators whose deviation
+    /// is above the deviation threshold of the config are outliers.
+    pub(crate) fn ranking_deviation(ranking: &[AccountId], top_ten: &[AccountId]) -> usize {
+        ranking.iter().filter(|miner| !top_ten.contains(miner)).count()
+    }
+
+    /// Takes `percentage` of the whole stake of `account`, active and unbonding, so that unstaking before
+    /// the settlement doesn't lower the penalty. The unbonding balance is slashed first. Returns the slashed amount.
+    fn slash(
+        stakes: &mut LookupMap<AccountId, Stake>,
+        unbonding_stakes: &mut LookupMap<AccountId, UnbondingStake>,
+        account: &AccountId,
+        percentage: u128,
+    ) -> NearToken {
+        let active = stakes.get(account).copied().unwrap_or(NearToken::from_yoctonear(0));
+        let unbonding = unbonding_stakes.get(account).map_or(NearToken::from_yoctonear(0), |unbonding| unbonding.amount);
+
+        let amount = active.saturating_add(unbonding).saturating_mul(percentage).saturating_div(100);
+        let from_unbonding = amount.min(unbonding);
+
+        if let Some(unbonding) = unbonding_stakes.get_mut(account) {
+            unbonding.amount = unbonding.amount.saturating_sub(from_unbonding);
+        }
+
+        if let Some(stake) = stakes.get_mut(account) {
+            *stake = stake.saturating_sub(amount.saturating_sub(from_unbonding));
+        }
+
+        amount
+    }
+}
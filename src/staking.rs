@@ -0,0 +1,383 @@
+use near_sdk::store::LookupMap;
+use near_sdk::{env, log, near_bindgen, require, AccountId, NearToken, Promise};
+
+use crate::registry::Registry;
+use crate::*;
+
+#[near_bindgen]
+impl Contract {
+    /// Adds the attached deposit to the stake of the caller as a miner or as a validator, whichever it is registered as.
+    /// Accounts registered as both pick the stake with `increase_miner_stake` or `increase_validator_stake`.
+    #[payable]
+    pub fn increase_stake(&mut self) -> IncreaseStakeResult {
+        let account = env::predecessor_account_id();
+
+        match (self.miners.contains_key(&account), self.validators.contains_key(&account)) {
+            (true, true) => panic!("Account is both a miner and a validator, use increase_miner_stake or increase_validator_stake"),
+            (true, false) => self.increase_miner_stake(),
+            (false, true) => self.increase_validator_stake(),
+            (false, false) => {
+                let deposit = env::attached_deposit();
+
+                require!(!deposit.is_zero(), "Deposit must be greater than zero");
+
+                log!("Account is not registered: {}", account);
+                Promise::new(account).transfer(deposit);
+                IncreaseStakeResult::NotRegistered
+            }
+        }
+    }
+
+    #[payable]
+    pub fn increase_miner_stake(&mut self) -> IncreaseStakeResult {
+        let miner = env::predecessor_account_id();
+        let deposit = env::attached_deposit();
+
+        require!(!deposit.is_zero(), "Deposit must be greater than zero");
+
+        let Some(stake) = self.miners.get_mut(&miner) else {
+            log!("Miner not registered: {}", miner);
+            Promise::new(miner).transfer(deposit);
+            return IncreaseStakeResult::NotRegistered;
+        };
+
+        *stake = stake.saturating_add(deposit);
+
+        let increase_miner_stake_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "1.0.0".to_string(),
+            event: EventLogVariant::IncreaseMinerStake(vec![IncreaseMinerStakeLog {
+                miner,
+                amount: deposit,
+                stake: *stake,
+            }]),
+        };
+
+        log!(&increase_miner_stake_log.to_string());
+
+        IncreaseStakeResult::Success
+    }
+
+    #[payable]
+    pub fn increase_validator_stake(&mut self) -> IncreaseStakeResult {
+        let validator = env::predecessor_account_id();
+        let deposit = env::attached_deposit();
+
+        require!(!deposit.is_zero(), "Deposit must be greater than zero");
+
+        let Some(stake) = self.validators.get_mut(&validator) else {
+            log!("Validator is not registered: {}", validator);
+            Promise::new(validator).transfer(deposit);
+            return IncreaseStakeResult::NotRegistered;
+        };
+
+        *stake = stake.saturating_add(deposit);
+
+        let increase_validator_stake_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "1.0.0".to_string(),
+            event: EventLogVariant::IncreaseValidatorStake(vec![IncreaseValidatorStakeLog {
+                validator,
+                amount: deposit,
+                stake: *stake,
+            }]),
+        };
+
+        log!(&increase_validator_stake_log.to_string());
+
+        IncreaseStakeResult::Success
+    }
+
+    pub fn unstake_miner(&mut self, amount: NearToken) -> UnstakeResult {
+        let miner = env::predecessor_account_id();
+
+        if !self.is_miner_registered(miner.clone()) {
+            log!("Miner not registered: {}", miner);
+            return UnstakeResult::NotRegistered;
+        }
+
+        let unbonding = match Self::unbond(
+            &mut self.miners,
+            &mut self.miners_unbonding,
+            &miner,
+            amount,
+            self.config.min_miner_stake,
+            self.config.unbonding_period,
+        ) {
+            Some(unbonding) => unbonding,
+            None => return UnstakeResult::InvalidAmount,
+        };
+
+        if !self.miners.contains_key(&miner) {
+            self.miner_registry.remove(&miner);
+        }
+
+        let unstake_miner_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "1.0.0".to_string(),
+            event: EventLogVariant::UnstakeMiner(vec![UnstakeMinerLog {
+                miner,
+                amount,
+                unlock_time: unbonding.unlock_time,
+            }]),
+        };
+
+        log!(&unstake_miner_log.to_string());
+
+        UnstakeResult::Success
+    }
+
+    pub fn unstake_validator(&mut self, amount: NearToken) -> UnstakeResult {
+        let validator = env::predecessor_account_id();
+
+        if !self.is_validator_registered(validator.clone()) {
+            log!("Validator is not registered: {}", validator);
+            return UnstakeResult::NotRegistered;
+        }
+
+        let unbonding = match Self::unbond(
+            &mut self.validators,
+            &mut self.validators_unbonding,
+            &validator,
+            amount,
+            self.config.min_validator_stake,
+            self.config.unbonding_period,
+        ) {
+            Some(unbonding) => unbonding,
+            None => return UnstakeResult::InvalidAmount,
+        };
+
+        if !self.validators.contains_key(&validator) {
+            self.validator_registry.remove(&validator);
+        }
+
+        let unstake_validator_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "1.0.0".to_string(),
+            event: EventLogVariant::UnstakeValidator(vec![UnstakeValidatorLog {
+                validator,
+                amount,
+                unlock_time: unbonding.unlock_time,
+            }]),
+        };
+
+        log!(&unstake_validator_log.to_string());
+
+        UnstakeResult::Success
+    }
+
+    pub fn withdraw_miner(&mut self) -> WithdrawResult {
+        let miner = env::predecessor_account_id();
+
+        if let Err(result) = self.check_withdrawable(&miner, true) {
+            return result;
+        }
+
+        let unbonding = self.miners_unbonding.remove(&miner).unwrap_or_else(|| panic!("Unbonding stake not found"));
+
+        Promise::new(miner.clone()).transfer(unbonding.amount);
+
+        let withdraw_miner_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "1.0.0".to_string(),
+            event: EventLogVariant::WithdrawMiner(vec![WithdrawMinerLog {
+                miner,
+                amount: unbonding.amount,
+            }]),
+        };
+
+        log!(&withdraw_miner_log.to_string());
+
+        WithdrawResult::Success
+    }
+
+    pub fn withdraw_validator(&mut self) -> WithdrawResult {
+        let validator = env::predecessor_account_id();
+
+        if let Err(result) = self.check_withdrawable(&validator, false) {
+            return result;
+        }
+
+        let unbonding = self
+            .validators_unbonding
+            .remove(&validator)
+            .unwrap_or_else(|| panic!("Unbonding stake not found"));
+
+        Promise::new(validator.clone()).transfer(unbonding.amount);
+
+        let withdraw_validator_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "1.0.0".to_string(),
+            event: EventLogVariant::WithdrawValidator(vec![WithdrawValidatorLog {
+                validator,
+                amount: unbonding.amount,
+            }]),
+        };
+
+        log!(&withdraw_validator_log.to_string());
+
+        WithdrawResult::Success
+    }
+
+    pub fn get_miner_stake(&self, miner_id: AccountId) -> Option<Stake> {
+        self.miners.get(&miner_id).copied()
+    }
+
+    pub fn get_validator_stake(&self, validator_id: AccountId) -> Option<Stake> {
+        self.validators.get(&validator_id).copied()
+    }
+
+    pub fn get_miners(&self, from_index: u32, limit: u32) -> Vec<ParticipantView> {
+        Self::participants(&self.miner_registry, &self.miners, from_index, limit)
+    }
+
+    pub fn get_validators(&self, from_index: u32, limit: u32) -> Vec<ParticipantView> {
+        Self::participants(&self.validator_registry, &self.validators, from_index, limit)
+    }
+
+    pub fn get_miner_count(&self) -> u32 {
+        self.miner_registry.len()
+    }
+
+    pub fn get_validator_count(&self) -> u32 {
+        self.validator_registry.len()
+    }
+
+    pub fn get_miner_unbonding_stake(&self, miner_id: AccountId) -> Option<UnbondingStake> {
+        self.miners_unbonding.get(&miner_id).cloned()
+    }
+
+    pub fn get_validator_unbonding_stake(&self, validator_id: AccountId) -> Option<UnbondingStake> {
+        self.validators_unbonding.get(&validator_id).cloned()
+    }
+}
+
+impl Contract {
+    fn participants(registry: &Registry, stakes: &LookupMap<AccountId, Stake>, from_index: u32, limit: u32) -> Vec<ParticipantView> {
+        registry
+            .page(from_index, limit)
+            .into_iter()
+            .map(|(account, registered_at)| ParticipantView {
+                stake: stakes.get(&account).copied().unwrap_or(NearToken::from_yoctonear(0)),
+                account,
+                registered_at,
+            })
+            .collect()
+    }
+
+    /// Moves `amount` from the active stake of `account` into its unbonding balance.
+    /// The account is unregistered when its whole stake is unstaked.
+    fn unbond(
+        stakes: &mut LookupMap<AccountId, Stake>,
+        unbonding_stakes: &mut LookupMap<AccountId, UnbondingStake>,
+        account: &AccountId,
+        amount: NearToken,
+        min_stake: NearToken,
+        unbonding_period: u64,
+    ) -> Option<UnbondingStake> {
+        let stake = *stakes.get(account).unwrap_or_else(|| panic!("Stake not found"));
+
+        if amount.is_zero() || amount > stake {
+            log!("Invalid amount to unstake: {}", amount);
+            return None;
+        }
+
+        let remaining = stake.saturating_sub(amount);
+
+        if !remaining.is_zero() && remaining < min_stake {
+            log!("Remaining stake is less than the minimum stake");
+            return None;
+        }
+
+        if remaining.is_zero() {
+            stakes.remove(account);
+        } else {
+            stakes.insert(account.clone(), remaining);
+        }
+
+        let previous = unbonding_stakes.get(account).map_or(NearToken::from_yoctonear(0), |unbonding| unbonding.amount);
+
+        // @dev Unstaking again restarts the unbonding period for the whole balance
+        let unbonding = UnbondingStake {
+            amount: previous.saturating_add(amount),
+            unlock_time: env::block_timestamp() + unbonding_period,
+        };
+
+        unbonding_stakes.insert(account.clone(), unbonding.clone());
+
+        Some(unbonding)
+    }
+
+    fn check_withdrawable(&mut self, account: &AccountId, is_miner: bool) -> Result<(), WithdrawResult> {
+        let unbonding = if is_miner {
+            self.miners_unbonding.get(account)
+        } else {
+            self.validators_unbonding.get(account)
+        };
+
+        let Some(unbonding) = unbonding else {
+            log!("Nothing to withdraw for: {}", account);
+            return Err(WithdrawResult::NothingToWithdraw);
+        };
+
+        if env::block_timestamp() < unbonding.unlock_time {
+            log!("Stake is still unbonding until: {}", unbonding.unlock_time);
+            return Err(WithdrawResult::StillUnbonding);
+        }
+
+        // @dev An unrevealed commit is pending until the request is settled for the account's role
+        let has_pending_commits = if is_miner {
+            Self::has_pending_commits(&self.requests, &mut self.miner_commits, account, |request| !request.miners_settled)
+        } else {
+            Self::has_pending_commits(&self.requests, &mut self.validator_commits, account, |request| !request.validators_settled)
+        };
+
+        if has_pending_commits {
+            log!("Account has unrevealed commits in open requests: {}", account);
+            return Err(WithdrawResult::PendingCommits);
+        }
+
+        Ok(())
+    }
+
+    /// Returns true when `account` has an unrevealed commit in a request for which `is_open` holds.
+    /// Commits of closed requests are pruned along the way.
+    fn has_pending_commits(
+        requests: &LookupMap<Hash, VersionedRequest>,
+        commits: &mut LookupMap<AccountId, Vec<Hash>>,
+        account: &AccountId,
+        is_open: fn(&Request) -> bool,
+    ) -> bool {
+        let Some(request_ids) = commits.get_mut(account) else {
+            return false;
+        };
+
+        request_ids.retain(|request_id| requests.get(request_id).is_some_and(|request| is_open(request)));
+
+        if request_ids.is_empty() {
+            commits.remove(account);
+            return false;
+        }
+
+        true
+    }
+
+    pub(crate) fn add_pending_commit(commits: &mut LookupMap<AccountId, Vec<Hash>>, account: AccountId, request_id: Hash) {
+        match commits.get_mut(&account) {
+            Some(request_ids) => request_ids.push(request_id),
+            None => {
+                commits.insert(account, vec![request_id]);
+            }
+        }
+    }
+
+    pub(crate) fn remove_pending_commit(commits: &mut LookupMap<AccountId, Vec<Hash>>, account: &AccountId, request_id: &Hash) {
+        if let Some(request_ids) = commits.get_mut(account) {
+            request_ids.retain(|id| id != request_id);
+
+            if request_ids.is_empty() {
+                commits.remove(account);
+            }
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use crate::*;
+
+pub const MINER_PROPOSALS_PREFIX: &[u8] = b"miner_proposal";
+pub const VALIDATOR_PROPOSALS_PREFIX: &[u8] = b"validator_proposal";
+pub const VOTES_FOR_MINERS_PREFIX: &[u8] = b"votes_miners";
+pub const SCORE_BREAKDOWN_PREFIX: &[u8] = b"score_breakdown";
+pub const MINER_VALUES_PREFIX: &[u8] = b"miner_values";
+
+impl Contract {
+    /// Storage prefix of a collection of `request_id`, so that concurrent requests don't share keys.
+    pub(crate) fn request_prefix(request_id: &str, collection: &[u8]) -> Vec<u8> {
+        [collection, b":", request_id.as_bytes()].concat()
+    }
+}
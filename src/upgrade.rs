@@ -0,0 +1,220 @@
+use near_sdk::borsh::{self, BorshDeserialize};
+use near_sdk::store::LookupMap;
+use near_sdk::{env, log, near_bindgen, require, AccountId, NearToken, Promise};
+use std::io::{self, Read};
+use std::ops::{Deref, DerefMut};
+
+use crate::storage::{MINER_PROPOSALS_PREFIX, MINER_VALUES_PREFIX, SCORE_BREAKDOWN_PREFIX, VALIDATOR_PROPOSALS_PREFIX, VOTES_FOR_MINERS_PREFIX};
+use crate::*;
+
+const STATE_KEY: &[u8] = b"STATE";
+
+/// Layout of the contract state of the baseline contract, which is the one deployed before the state was versioned.
+#[derive(BorshDeserialize)]
+struct ContractV0 {
+    protocols: LookupMap<AccountId, VersionedProtocol>,
+    requests: LookupMap<Hash, VersionedRequest>,
+    miners: LookupMap<AccountId, Stake>,
+    validators: LookupMap<AccountId, Stake>,
+}
+
+#[near_bindgen]
+impl Contract {
+    /// Deploys the code passed as the raw input of the call and migrates the state with it.
+    pub fn upgrade(&mut self) -> Promise {
+        self.assert_any_role(&[Role::Owner]);
+
+        let code = env::input().unwrap_or_else(|| panic!("Contract code not found"));
+
+        Promise::new(env::current_account_id()).deploy_contract(code).function_call(
+            "migrate".to_string(),
+            Vec::new(),
+            NearToken::from_yoctonear(0),
+            MIGRATE_GAS,
+        )
+    }
+
+    /// Reads the state written by the previous code and converts it to the current layout.
+    /// Requests and protocols are converted when they are read, so only the contract itself is rewritten here.
+    #[allow(clippy::use_self)]
+    #[private]
+    #[init(ignore_state)]
+    pub fn migrate() -> Self {
+        let state = env::storage_read(STATE_KEY).unwrap_or_else(|| panic!("Contract state not found"));
+
+        if let Ok(contract) = Self::try_from_slice(&state) {
+            log!("Contract state already migrated");
+            return contract;
+        }
+
+        let legacy = ContractV0::try_from_slice(&state).unwrap_or_else(|_| panic!("Unknown contract state layout"));
+
+        // @dev The rest of the state starts empty. The baseline had no owner, so the contract account,
+        // the only one that can call this method, becomes the owner
+        let contract = Self {
+            protocols: legacy.protocols,
+            requests: legacy.requests,
+            miners: legacy.miners,
+            validators: legacy.validators,
+            ..Self::new()
+        };
+
+        let state_migrated_log = EventLog {
+            standard: "emip001".to_string(),
+            version: "1.0.0".to_string(),
+            event: EventLogVariant::StateMigrated(vec![StateMigratedLog { version: contract.version }]),
+        };
+
+        log!(&state_migrated_log.to_string());
+
+        contract
+    }
+
+    /// Lists miners and validators registered by the baseline contract in `get_miners` and `get_validators`.
+    ///
+    /// The baseline kept them in maps that can't be iterated, so their accounts are passed in pages of at most
+    /// `MAX_BACKFILLED_ACCOUNTS`. Accounts without a stake or already listed are skipped. Returns the number of accounts added.
+    pub fn backfill_registry(&mut self, miners: Vec<AccountId>, validators: Vec<AccountId>) -> u32 {
+        self.assert_any_role(&[Role::Owner, Role::Admin]);
+
+        require!(
+            miners.len() + validators.len() <= MAX_BACKFILLED_ACCOUNTS,
+            format!("At most {} accounts can be backfilled at once", MAX_BACKFILLED_ACCOUNTS)
+        );
+
+        // @dev The baseline didn't store when accounts registered, so they are listed as registered at 0
+        let added_miners = miners
+            .into_iter()
+            .filter(|miner| self.miners.contains_key(miner))
+            .filter(|miner| self.miner_registry.insert(miner.clone(), 0))
+            .count();
+        let added_validators = validators
+            .into_iter()
+            .filter(|validator| self.validators.contains_key(validator))
+            .filter(|validator| self.validator_registry.insert(validator.clone(), 0))
+            .count();
+
+        log!("Accounts added to the registry: {} miners, {} validators", added_miners, added_validators);
+
+        (added_miners + added_validators) as u32
+    }
+
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn get_state_version(&self) -> StateVersion {
+        self.version
+    }
+}
+
+/// Reads a value whose first bytes were already taken from `reader` to find its version.
+fn read_unversioned<T: BorshDeserialize, R: Read>(taken: &[u8], reader: &mut R) -> io::Result<T> {
+    T::deserialize_reader(&mut taken.chain(reader))
+}
+
+// @dev Unversioned requests and protocols, written by the baseline contract, start with the length of an account id,
+// which is at least 2 and at most 64, as a little endian u32. Their first byte can't be the version tag 0.
+impl BorshDeserialize for VersionedRequest {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        match u8::deserialize_reader(reader)? {
+            0 => Ok(Self::V1(Request::deserialize_reader(reader)?)),
+            tag => Ok(Self::V1(read_unversioned::<RequestV0, R>(&[tag], reader)?.into())),
+        }
+    }
+}
+
+impl BorshDeserialize for VersionedProtocol {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        match u8::deserialize_reader(reader)? {
+            0 => Ok(Self::V1(Protocol::deserialize_reader(reader)?)),
+            tag => Ok(Self::V1(read_unversioned::<ProtocolV0, R>(&[tag], reader)?.into())),
+        }
+    }
+}
+
+impl From<Request> for VersionedRequest {
+    fn from(request: Request) -> Self {
+        Self::V1(request)
+    }
+}
+
+// @dev Baseline requests shared their proposal and vote maps, keyed only by account, so the proposals and votes
+// of a request can't be told apart from the ones of other requests. Baseline requests keep their published top ten
+// and get their own empty maps, and they are closed as finalized and settled, since none of their entries is recovered
+impl From<RequestV0> for Request {
+    fn from(request: RequestV0) -> Self {
+        let request_id = request.request_id;
+
+        Self {
+            sender: request.sender,
+            start_time: request.start_time,
+            miners_proposals: LookupMap::new(Contract::request_prefix(&request_id, MINER_PROPOSALS_PREFIX)),
+            validators_proposals: LookupMap::new(Contract::request_prefix(&request_id, VALIDATOR_PROPOSALS_PREFIX)),
+            votes_for_miners: LookupMap::new(Contract::request_prefix(&request_id, VOTES_FOR_MINERS_PREFIX)),
+            score_breakdown: LookupMap::new(Contract::request_prefix(&request_id, SCORE_BREAKDOWN_PREFIX)),
+            miner_keys: Vec::new(),
+            top_ten: request.top_ten,
+            committed_miners: Vec::new(),
+            committed_validators: Vec::new(),
+            miners_settled: true,
+            validators_settled: true,
+            is_finalized: true,
+            bounty: NearToken::from_yoctonear(0),
+            decision: None,
+            callback_status: None,
+            schedule: DEFAULT_PHASE_SCHEDULE,
+            options: DEFAULT_OPTIONS.map(String::from).to_vec(),
+            numeric: None,
+            miner_values: LookupMap::new(Contract::request_prefix(&request_id, MINER_VALUES_PREFIX)),
+            request_id,
+        }
+    }
+}
+
+impl From<ProtocolV0> for Protocol {
+    fn from(protocol: ProtocolV0) -> Self {
+        Self {
+            account: protocol.account,
+            culture: protocol.culture,
+            modules: protocol.modules,
+            registration_fee: protocol.registration_fee,
+            schedule: DEFAULT_PHASE_SCHEDULE,
+        }
+    }
+}
+
+impl From<Protocol> for VersionedProtocol {
+    fn from(protocol: Protocol) -> Self {
+        Self::V1(protocol)
+    }
+}
+
+impl Deref for VersionedRequest {
+    type Target = Request;
+
+    fn deref(&self) -> &Request {
+        let Self::V1(request) = self;
+        request
+    }
+}
+
+impl DerefMut for VersionedRequest {
+    fn deref_mut(&mut self) -> &mut Request {
+        let Self::V1(request) = self;
+        request
+    }
+}
+
+impl Deref for VersionedProtocol {
+    type Target = Protocol;
+
+    fn deref(&self) -> &Protocol {
+        let Self::V1(protocol) = self;
+        protocol
+    }
+}
+
+impl DerefMut for VersionedProtocol {
+    fn deref_mut(&mut self) -> &mut Protocol {
+        let Self::V1(protocol) = self;
+        protocol
+    }
+}
@@ -0,0 +1,124 @@
+use near_sdk::{env, near_bindgen, AccountId};
+
+use crate::*;
+
+#[near_bindgen]
+impl Contract {
+    pub fn get_request(&self, request_id: Hash) -> Option<RequestView> {
+        self.requests.get(&request_id).map(|request| Self::request_view(request))
+    }
+
+    /// Requests sent by `account` from `from_index` on, at most `limit` of them, in the order they were created.
+    /// With a stage filter only the requests in that stage among them are returned, and the limit is capped
+    /// at `MAX_SCANNED_REQUESTS`, so that a page never reads more requests than that.
+    pub fn get_requests_by_protocol(&self, account: AccountId, from_index: u32, limit: u32, stage_filter: Option<StageFilter>) -> Vec<RequestView> {
+        let Some(request_ids) = self.protocol_requests.get(&account) else {
+            return Vec::new();
+        };
+
+        let limit = if stage_filter.is_some() { limit.min(MAX_SCANNED_REQUESTS) } else { limit };
+
+        request_ids
+            .iter()
+            .skip(from_index as usize)
+            .take(limit as usize)
+            .filter_map(|request_id| self.requests.get(request_id))
+            .map(|request| Self::request_view(request))
+            .filter(|view| stage_filter.is_none_or(|filter| Self::matches_stage(filter, &view.stage)))
+            .collect()
+    }
+
+    /// Outcome of a finalized request. Returns `None` until the request is finalized.
+    pub fn get_results(&self, request_id: Hash) -> Option<RequestResults> {
+        let request = self.requests.get(&request_id).filter(|request| request.is_finalized)?;
+
+        Some(RequestResults {
+            request_id,
+            top_ten: request.top_ten.clone(),
+            decision: request.decision.clone(),
+        })
+    }
+
+    pub fn get_top_miners(&self, request_id: Hash) -> Option<Vec<(AccountId, u32)>> {
+        let request = self.requests.get(&request_id).filter(|request| request.is_finalized)?;
+
+        Some(request.top_ten.clone())
+    }
+
+    /// Top ten of a finalized request, empty until the request is finalized.
+    /// Kept for compatibility, use `get_top_miners` instead.
+    pub fn get_top_10_voters(&self, request_id: String) -> Vec<(AccountId, u32)> {
+        self.get_top_miners(request_id).unwrap_or_default()
+    }
+
+    /// Id of the request number `nonce` of `sender`, so that clients can compute it before sending the request.
+    pub fn get_request_id(&self, sender: AccountId, message: String, nonce: u64) -> Hash {
+        Self::derive_request_id(&sender, &message, nonce)
+    }
+
+    /// Id that the baseline contract gave to the request of `sender` with `message`, derived from them only.
+    pub fn get_legacy_request_id(&self, sender: AccountId, message: String) -> Hash {
+        hex::encode(env::keccak256(format!("{}{}", sender, message).as_bytes()))
+    }
+
+    /// Nonce that the next request of `account` will have.
+    pub fn get_request_nonce(&self, account: AccountId) -> u64 {
+        self.request_nonce(&account)
+    }
+
+    pub fn get_votes_for_miner(&self, request_id: Hash, miner_id: AccountId) -> Option<u32> {
+        self.requests.get(&request_id)?.votes_for_miners.get(&miner_id).copied()
+    }
+}
+
+impl Contract {
+    fn request_view(request: &Request) -> RequestView {
+        let commit_miners_deadline = request.start_time + request.schedule.commit_miners;
+        let reveal_miners_deadline = commit_miners_deadline + request.schedule.reveal_miners;
+        let commit_validators_deadline = reveal_miners_deadline + request.schedule.commit_validators;
+        let reveal_validators_deadline = commit_validators_deadline + request.schedule.reveal_validators;
+
+        let miners_revealed = request
+            .committed_miners
+            .iter()
+            .filter(|miner| request.miners_proposals.get(*miner).is_some_and(|proposal| proposal.is_revealed))
+            .count();
+        let validators_revealed = request
+            .committed_validators
+            .iter()
+            .filter(|validator| request.validators_proposals.get(*validator).is_some_and(|proposal| proposal.is_revealed))
+            .count();
+
+        RequestView {
+            request_id: request.request_id.clone(),
+            sender: request.sender.clone(),
+            start_time: request.start_time,
+            stage: Self::get_stage(request),
+            commit_miners_deadline,
+            reveal_miners_deadline,
+            commit_validators_deadline,
+            reveal_validators_deadline,
+            miners_committed: request.committed_miners.len() as u32,
+            miners_revealed: miners_revealed as u32,
+            validators_committed: request.committed_validators.len() as u32,
+            validators_revealed: validators_revealed as u32,
+            bounty: request.bounty,
+            options: request.options.clone(),
+            numeric: request.numeric,
+            top_ten: request.is_finalized.then(|| request.top_ten.clone()),
+            decision: request.decision.clone(),
+        }
+    }
+
+    /// Open requests accept miner answers, requests in progress are being revealed or ranked.
+    const fn matches_stage(filter: StageFilter, stage: &RequestState) -> bool {
+        match filter {
+            StageFilter::Open => matches!(stage, RequestState::NonStarted | RequestState::CommitMiners),
+            StageFilter::InProgress => matches!(
+                stage,
+                RequestState::RevealMiners | RequestState::CommitValidators | RequestState::RevealValidators
+            ),
+            StageFilter::Ended => matches!(stage, RequestState::Ended),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use near_sdk::{near_bindgen, AccountId};
+
+use crate::*;
+
+#[near_bindgen]
+impl Contract {
+    pub fn get_score_breakdown(&self, request_id: Hash, miner_id: AccountId) -> Option<Vec<VoteScore>> {
+        self.requests.get(&request_id)?.score_breakdown.get(&miner_id).cloned()
+    }
+}
+
+impl Contract {
+    /// Weight of a validator vote according to `weighting`, measured in `VOTE_WEIGHT_UNIT`s of stake
+    /// and at most `cap` with the capped weighting. Every registered validator has a weight of at least 1.
+    pub(crate) fn vote_weight(stake: Stake, weighting: VoteWeighting, cap: u32) -> u32 {
+        let units = (stake.as_yoctonear() / VOTE_WEIGHT_UNIT.as_yoctonear()).max(1);
+
+        let weight = match weighting {
+            VoteWeighting::Linear => units,
+            VoteWeighting::SquareRoot => units.isqrt(),
+            VoteWeighting::Capped => units.min(u128::from(cap)),
+        };
+
+        u32::try_from(weight).unwrap_or(u32::MAX)
+    }
+
+    /// Adds the score of a revealed ranking to the votes of every ranked miner and records
+    /// how much each validator contributed to it.
+    pub(crate) fn add_votes(request: &mut Request, validator: &AccountId, ranking: &[AccountId], weight: u32) {
+        for (position, miner) in ranking.iter().enumerate() {
+            let points = POSITION_POINTS.get(position).copied().unwrap_or(0);
+            let score = points.saturating_mul(weight);
+
+            match request.votes_for_miners.get_mut(miner) {
+                Some(votes) => *votes = votes.saturating_add(score),
+                None => {
+                    request.votes_for_miners.insert(miner.clone(), score);
+                    request.miner_keys.push(miner.clone());
+                }
+            }
+
+            let vote_score = VoteScore {
+                validator: validator.clone(),
+                position: position as u32,
+                points,
+                weight,
+                score,
+            };
+
+            match request.score_breakdown.get_mut(miner) {
+                Some(breakdown) => breakdown.push(vote_score),
+                None => {
+                    request.score_breakdown.insert(miner.clone(), vec![vote_score]);
+                }
+            }
+        }
+    }
+}
@@ -16,11 +16,14 @@ pub const DEFAULT_DEPOSIT_MINER: NearToken = NearToken::from_near(1);
 pub const DEFAULT_DEPOSIT_VALIDATOR: NearToken = NearToken::from_near(10);
 pub const DEFAULT_DEPOSIT_PROTOCOL: NearToken = NearToken::from_near(5);
 pub const DEFAULT_MESSAGE_TO_REQUEST: &str = "Should we add this new NFT to our protocol?";
-pub const DEFAULT_REQUEST_ID: &str = "73ead60176d724e462dbfa8d49506177bb13bec748cf5af5019b6d1da63e204b";
-pub const DEFAULT_MINER_ANSWER: &str = "422fa60e22dc75c98d21bb975323c5c0b854d6b0b7a63d6446b3bbb628b65a5b";
-pub const DEFAULT_VALIDATOR_ANSWER: &str = "475e370cb76d086eb73e1a239ea10df551dc75a6596f61c7c2b473669570eb3b";
+pub const DEFAULT_REQUEST_ID: &str = "1b7b4a1997b364a89227157bb5e816f311616fdbf6206195c68a7c95bf1c6f43";
+pub const DEFAULT_MINER_ANSWER: &str = "5007f62132000a5b2b36782f9f9ea9348e22ff99e9d678aff593311a7643f5ef";
+pub const DEFAULT_VALIDATOR_ANSWER: &str = "499eb74ad3a0760e1c5faeae664f95f3f43a4c15395b0d97d9249d2c4932c83c";
 pub const REVEAL_MINER_TIME: u64 = 100000000 + (4 * 60 * 1_000_000_000);
 pub const COMMIT_VALIDATOR_TIME: u64 = 100000000 + (7 * 60 * 1_000_000_000);
 pub const REVEAL_VALIDATOR_TIME: u64 = 100000000 + (8 * 60 * 1_000_000_000);
 pub const REVEAL_TOPTEN_TIME: u64 = 100000000 + (10 * 60 * 1_000_000_000);
 pub const DEFAULT_CULTURE: &str = "Governance decision";
+pub const DEFAULT_REVEAL_MESSAGE: &str = "It's a cool NFT";
+pub const CONTRACT_ACCOUNT_ID: &str = "alice.near"; // Current account of the default testing context
+pub const OWNER: &str = "owner.near";
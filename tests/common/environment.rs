@@ -1,4 +1,4 @@
-use near_sdk::{test_utils::VMContextBuilder, testing_env, AccountId, NearToken};
+use near_sdk::{test_utils::VMContextBuilder, testing_env, AccountId, NearToken, PromiseResult, RuntimeFeesConfig};
 
 use super::constants::{DEFAULT_DEPOSIT, DEFAULT_TIMESTAMP};
 
@@ -6,6 +6,8 @@ pub struct Environment {
     account_id: AccountId,
     block_timestamp: u64,
     attached_deposit: NearToken,
+    promise_results: Vec<PromiseResult>,
+    input: Vec<u8>,
 }
 
 impl Environment {
@@ -15,6 +17,8 @@ impl Environment {
             account_id,
             block_timestamp: DEFAULT_TIMESTAMP,
             attached_deposit: DEFAULT_DEPOSIT,
+            promise_results: Vec::new(),
+            input: Vec::new(),
         }
     }
     #[allow(clippy::missing_const_for_fn)]
@@ -27,6 +31,16 @@ impl Environment {
         self.attached_deposit = attached_deposit;
         self
     }
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn with_promise_results(mut self, promise_results: Vec<PromiseResult>) -> Self {
+        self.promise_results = promise_results;
+        self
+    }
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn with_input(mut self, input: Vec<u8>) -> Self {
+        self.input = input;
+        self
+    }
 
     pub fn create(self) {
         let mut builder = VMContextBuilder::new();
@@ -34,7 +48,14 @@ impl Environment {
             .predecessor_account_id(self.account_id)
             .block_timestamp(self.block_timestamp)
             .attached_deposit(self.attached_deposit);
+        builder.context.input = self.input;
 
-        testing_env!(builder.build());
+        testing_env!(
+            builder.build(),
+            near_sdk::test_vm_config(),
+            RuntimeFeesConfig::test(),
+            Default::default(),
+            self.promise_results
+        );
     }
 }
@@ -0,0 +1,140 @@
+use near_sdk::json_types::I128;
+use near_sdk::{env, AccountId, NearToken};
+
+use earthmind_rs::{CommitMinerResult, CommitValidatorResult, Contract, Module, NumericAnswer, RevealMinerResult, RevealValidatorResult};
+
+use super::constants::{
+    COMMIT_VALIDATOR_TIME, DEFAULT_CULTURE, DEFAULT_DEPOSIT_MINER, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_DEPOSIT_VALIDATOR, DEFAULT_MESSAGE_TO_REQUEST,
+    DEFAULT_REQUEST_ID, DEFAULT_REVEAL_MESSAGE, REVEAL_MINER_TIME, REVEAL_VALIDATOR_TIME,
+};
+use super::environment::Environment;
+use super::utils::get_default_protocol_account;
+
+pub fn generate_miners(count: usize) -> Vec<AccountId> {
+    (1..=count).map(|index| format!("miner{}.near", index).parse().unwrap()).collect()
+}
+
+pub fn generate_validators(count: usize) -> Vec<AccountId> {
+    (1..=count).map(|index| format!("validator{}.near", index).parse().unwrap()).collect()
+}
+
+pub fn hash_miner_answer(request_id: &str, miner: &AccountId, answer: u32, message: &str) -> String {
+    let concatenated_answer = format!("{}{}{}{}", request_id, miner, answer, message);
+    hex::encode(env::keccak256(concatenated_answer.as_bytes()))
+}
+
+pub fn hash_miner_value(request_id: &str, miner: &AccountId, value: i128, message: &str) -> String {
+    let concatenated_answer = format!("{}{}{}{}", request_id, miner, value, message);
+    hex::encode(env::keccak256(concatenated_answer.as_bytes()))
+}
+
+pub fn hash_validator_answer(request_id: &str, validator: &AccountId, answer: &[AccountId], message: &str) -> String {
+    let mut concatenated_answer: Vec<u8> = Vec::new();
+
+    concatenated_answer.extend_from_slice(request_id.as_bytes());
+    concatenated_answer.extend_from_slice(validator.as_bytes());
+    answer.iter().for_each(|miner| concatenated_answer.extend_from_slice(miner.as_bytes()));
+    concatenated_answer.extend_from_slice(message.as_bytes());
+
+    hex::encode(env::keccak256(&concatenated_answer))
+}
+
+pub fn register_protocol_and_request(contract: &mut Contract) {
+    register_protocol_and_request_with_bounty(contract, NearToken::from_yoctonear(0));
+}
+
+pub fn register_protocol_and_request_with_bounty(contract: &mut Contract, bounty: NearToken) {
+    register_protocol_and_request_with(contract, bounty, None);
+}
+
+pub fn register_protocol_and_request_with_options(contract: &mut Contract, options: &[&str]) {
+    let options = options.iter().map(|option| option.to_string()).collect();
+    register_protocol_and_request_with(contract, NearToken::from_yoctonear(0), Some(options));
+}
+
+pub fn register_protocol_and_numeric_request(contract: &mut Contract, answer: NumericAnswer) {
+    register_default_protocol(contract);
+
+    Environment::with_account(get_default_protocol_account()).create();
+    contract.request_numeric_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), answer);
+}
+
+fn register_protocol_and_request_with(contract: &mut Contract, bounty: NearToken, options: Option<Vec<String>>) {
+    register_default_protocol(contract);
+
+    Environment::with_account(get_default_protocol_account()).with_attached_deposit(bounty).create();
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), options);
+}
+
+fn register_default_protocol(contract: &mut Contract) {
+    Environment::with_account(get_default_protocol_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+
+    let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
+    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
+}
+
+pub fn register_and_commit_miners(contract: &mut Contract, miners: &[AccountId], answer: u32) {
+    for miner in miners {
+        Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+        contract.register_miner();
+
+        let hash = hash_miner_answer(DEFAULT_REQUEST_ID, miner, answer, DEFAULT_REVEAL_MESSAGE);
+        assert_eq!(contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), hash), CommitMinerResult::Success);
+    }
+}
+
+pub fn reveal_miners(contract: &mut Contract, miners: &[AccountId], answer: u32) {
+    for miner in miners {
+        Environment::with_account(miner.clone()).with_block_timestamp(REVEAL_MINER_TIME).create();
+
+        let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, DEFAULT_REVEAL_MESSAGE.to_string());
+        assert_eq!(result, RevealMinerResult::Success);
+    }
+}
+
+pub fn register_and_commit_miners_value(contract: &mut Contract, miners: &[AccountId], value: i128) {
+    for miner in miners {
+        Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+        contract.register_miner();
+
+        let hash = hash_miner_value(DEFAULT_REQUEST_ID, miner, value, DEFAULT_REVEAL_MESSAGE);
+        assert_eq!(contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), hash), CommitMinerResult::Success);
+    }
+}
+
+pub fn reveal_miners_value(contract: &mut Contract, miners: &[AccountId], value: i128) {
+    for miner in miners {
+        Environment::with_account(miner.clone()).with_block_timestamp(REVEAL_MINER_TIME).create();
+
+        let result = contract.reveal_value_by_miner(DEFAULT_REQUEST_ID.to_string(), I128(value), DEFAULT_REVEAL_MESSAGE.to_string());
+        assert_eq!(result, RevealMinerResult::Success);
+    }
+}
+
+pub fn register_and_commit_validator(contract: &mut Contract, validator: &AccountId, ranking: &[AccountId]) {
+    Environment::with_account(validator.clone())
+        .with_attached_deposit(DEFAULT_DEPOSIT_VALIDATOR)
+        .create();
+    contract.register_validator();
+
+    Environment::with_account(validator.clone())
+        .with_block_timestamp(COMMIT_VALIDATOR_TIME)
+        .create();
+
+    let hash = hash_validator_answer(DEFAULT_REQUEST_ID, validator, ranking, DEFAULT_REVEAL_MESSAGE);
+    assert_eq!(
+        contract.commit_by_validator(DEFAULT_REQUEST_ID.to_string(), hash),
+        CommitValidatorResult::Success
+    );
+}
+
+pub fn reveal_validator(contract: &mut Contract, validator: &AccountId, ranking: &[AccountId]) {
+    Environment::with_account(validator.clone())
+        .with_block_timestamp(REVEAL_VALIDATOR_TIME)
+        .create();
+
+    let result = contract.reveal_by_validator(DEFAULT_REQUEST_ID.to_string(), ranking.to_vec(), DEFAULT_REVEAL_MESSAGE.to_string());
+    assert_eq!(result, RevealValidatorResult::Success);
+}
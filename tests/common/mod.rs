@@ -1,4 +1,5 @@
 pub mod constants;
 pub mod environment;
+pub mod lifecycle;
 pub mod types;
 pub mod utils;
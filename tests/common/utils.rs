@@ -2,7 +2,9 @@ use super::{
     constants::{DEFAULT_MINER_ACCOUNT_ID, DEFAULT_PROTOCOL_ACCOUNT_ID, DEFAULT_VALIDATOR_ACCOUNT_ID},
     types::Log,
 };
-use near_sdk::{test_utils::get_logs, AccountId};
+use near_sdk::mock::MockAction;
+use near_sdk::test_utils::{get_created_receipts, get_logs};
+use near_sdk::{AccountId, NearToken};
 use serde_json::{json, Value};
 
 pub fn get_account_for_protocol(account: &str) -> AccountId {
@@ -62,20 +64,28 @@ pub fn group_registered_miners() -> Vec<AccountId> {
 
 pub fn default_miners_commit_answer() -> Vec<String> {
     let value = vec![
-        "422fa60e22dc75c98d21bb975323c5c0b854d6b0b7a63d6446b3bbb628b65a5b".to_string(),
-        "c06a8aabd77066edbee09e50289c3cc1a3a57514bea9a9bcbb244559816ccf26".to_string(),
-        "7fa05dacffc6bd12f708929057f259ab61505b6f21e45450d4c04509e0071e49".to_string(),
-        "49284c05ff843c5a947bb041fafab9eb77685463f7c1e285274b878f2a2ee8a1".to_string(),
-        "859597f6b7e5bc55a5ef630f6b1a7a8800740f8b77e6213fe314029010b132d4".to_string(),
-        "51dde426921f48e3954ced820ec684bf480d66f0594ff5ffd85fd55e7a6b1736".to_string(),
-        "24452398ffcafe810ec9c268d7637c9fafb1d407a76a7f219c176d4ae7d7e570".to_string(),
-        "c062ac786582a16be008945533fe2db95de5d841dba864523bc3123c5642d346".to_string(),
-        "21aeb50d9b89cfceccdf33741d037c66641e59acdf21f97457627d7f85db206e".to_string(),
-        "47fb74320537a28d0130c7b2f00d4a75be7bdcf14b15930e36c336151de6dddc".to_string(),
+        "5007f62132000a5b2b36782f9f9ea9348e22ff99e9d678aff593311a7643f5ef".to_string(),
+        "80a56fc91522e5543f249c723f5697bf0aae1a5b8edbf45de29993fa45b42569".to_string(),
+        "d9a32ea6919a15159140a8964f8582e3556bd35ebc1a06bab882ce5ac9045876".to_string(),
+        "8fc4a8d156eb490de9669162f1a3ed75723c05501b8312c93d39b4a1f0866bf5".to_string(),
+        "41dc0781fde8407d7c87dfb4a9de6d55c45d0b9b508ef9a01e2b21053d509f56".to_string(),
+        "e0d18eac0de4ea0fde33c75ae5d774ad2929c827fe508fa9b088fb39dc99fbf2".to_string(),
+        "8b5e3a3fbb3b8671af7faa0e345eb4651a11c30d3db1b61438edd71f15a1a90e".to_string(),
+        "b876c0e04f8a1d94794dc2b127aeb90f0e9b8bf9feb55475abda4fbcc5fa8a31".to_string(),
+        "2d0da685a4536c7d1979d6e1e6dce5f0ef78346fb0d92a4f4cb794f9ab8131c8".to_string(),
+        "4bcc9965d8fa367761bfe7c631fbb0d4770c7439cbd407771fde707d811646d7".to_string(),
     ];
     value
 }
 
+// @dev The reveal_miner event changed its answer to an option index with multiple choice
+fn event_version(event_name: &str) -> &'static str {
+    match event_name {
+        "reveal_miner" => "2.0.0",
+        _ => "1.0.0",
+    }
+}
+
 pub fn assert_log(event_name: &str, data: Vec<(&str, &str)>) {
     let logs = get_logs();
     assert_eq!(logs.len(), 1);
@@ -87,7 +97,7 @@ pub fn assert_log(event_name: &str, data: Vec<(&str, &str)>) {
 
     let expected_event = json!({
         "standard": "emip001",
-        "version": "1.0.0",
+        "version": event_version(event_name),
         "event": event_name,
         "data": [data_map]
     });
@@ -114,7 +124,7 @@ pub fn assert_logs(expected_logs: Vec<Log>) {
 
                 let expected_event = json!({
                     "standard": "emip001",
-                    "version": "1.0.0",
+                    "version": event_version(event_name),
                     "event": event_name,
                     "data": [data_map]
                 });
@@ -132,3 +142,32 @@ pub fn assert_logs(expected_logs: Vec<Log>) {
         }
     }
 }
+
+pub fn assert_transfers(expected_transfers: Vec<(AccountId, NearToken)>) {
+    let receipts: Vec<_> = get_created_receipts()
+        .into_iter()
+        .filter(|receipt| receipt.actions.iter().any(|action| matches!(action, MockAction::Transfer { .. })))
+        .collect();
+    assert_eq!(receipts.len(), expected_transfers.len());
+
+    for (receipt, (receiver_id, amount)) in receipts.iter().zip(expected_transfers) {
+        assert_eq!(receipt.receiver_id, receiver_id);
+        assert!(matches!(receipt.actions[..], [MockAction::Transfer { deposit, .. }] if deposit == amount));
+    }
+}
+
+pub fn assert_function_calls(expected_calls: Vec<(AccountId, &str)>) {
+    let calls: Vec<(AccountId, String)> = get_created_receipts()
+        .into_iter()
+        .flat_map(|receipt| {
+            receipt.actions.into_iter().filter_map(move |action| match action {
+                MockAction::FunctionCallWeight { method_name, .. } => Some((receipt.receiver_id.clone(), String::from_utf8(method_name).unwrap())),
+                _ => None,
+            })
+        })
+        .collect();
+
+    let expected_calls: Vec<(AccountId, String)> = expected_calls.into_iter().map(|(account, method)| (account, method.to_string())).collect();
+
+    assert_eq!(calls, expected_calls);
+}
@@ -0,0 +1,116 @@
+use near_sdk::NearToken;
+use serde_json::json;
+
+use common::constants::{DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_REQUEST_ID, REVEAL_TOPTEN_TIME};
+use common::environment::Environment;
+use common::lifecycle::{
+    generate_miners, generate_validators, register_and_commit_miners, register_and_commit_validator, register_protocol_and_request_with_bounty, reveal_miners,
+    reveal_validator,
+};
+use common::types::Log;
+use common::utils::{assert_logs, assert_transfers, get_default_protocol_account};
+
+use earthmind_rs::{Config, Contract, UpdateConfigResult, DEFAULT_CONFIG, REWARD_PER_REQUEST};
+
+pub mod common;
+
+const BOUNTY: NearToken = NearToken::from_near(3);
+
+#[test]
+fn test_bounty_is_added_to_the_rewards() {
+    let mut contract = Contract::new();
+    register_protocol_and_request_with_bounty(&mut contract, BOUNTY);
+
+    let miners = generate_miners(10);
+    register_and_commit_miners(&mut contract, &miners, 0);
+    reveal_miners(&mut contract, &miners, 0);
+
+    let validator = generate_validators(1)[0].clone();
+    register_and_commit_validator(&mut contract, &validator, &miners);
+    reveal_validator(&mut contract, &validator, &miners);
+
+    Environment::with_account(validator.clone()).with_block_timestamp(REVEAL_TOPTEN_TIME).create();
+    contract.finalize_request(DEFAULT_REQUEST_ID.to_string());
+
+    // @dev The pool is the base reward plus the bounty: 4 near split 70% / 30%
+    for miner in miners {
+        assert_eq!(contract.get_pending_rewards(miner), NearToken::from_millinear(280));
+    }
+
+    assert_eq!(contract.get_pending_rewards(validator), NearToken::from_millinear(1200));
+    assert_eq!(contract.get_treasury_balance(), DEFAULT_DEPOSIT_PROTOCOL.saturating_sub(REWARD_PER_REQUEST));
+    assert_transfers(vec![]);
+}
+
+#[test]
+fn test_bounty_is_refunded_when_no_validator_reveals() {
+    let mut contract = Contract::new();
+    register_protocol_and_request_with_bounty(&mut contract, BOUNTY);
+
+    let miners = generate_miners(10);
+    register_and_commit_miners(&mut contract, &miners, 0);
+    reveal_miners(&mut contract, &miners, 0);
+
+    let validator = generate_validators(1)[0].clone();
+    register_and_commit_validator(&mut contract, &validator, &miners);
+
+    Environment::with_account(validator.clone()).with_block_timestamp(REVEAL_TOPTEN_TIME).create();
+    contract.finalize_request(DEFAULT_REQUEST_ID.to_string());
+
+    let protocol = get_default_protocol_account();
+
+    assert_logs(vec![
+        Log::Event {
+            event_name: "topten_miners".to_string(),
+            data: vec![("request_id", json![DEFAULT_REQUEST_ID]), ("topten", json![[]])],
+        },
+        Log::Event {
+            event_name: "decision_finalized".to_string(),
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("decision", json!({ "option": null, "votes": [0, 0], "value": null })),
+            ],
+        },
+        Log::Event {
+            event_name: "refund_bounty".to_string(),
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("account", json![protocol]),
+                ("amount", json![BOUNTY]),
+            ],
+        },
+    ]);
+    assert_transfers(vec![(protocol, BOUNTY)]);
+
+    assert_eq!(contract.get_pending_rewards(validator), NearToken::from_yoctonear(0));
+    assert_eq!(contract.get_treasury_balance(), DEFAULT_DEPOSIT_PROTOCOL);
+}
+
+#[test]
+fn test_bounty_is_refunded_when_fewer_validators_reveal_than_configured() {
+    let mut contract = Contract::new();
+
+    Environment::with_account(contract.get_owner()).create();
+    let config = Config {
+        bounty_min_revealed_validators: 2,
+        ..DEFAULT_CONFIG
+    };
+    assert_eq!(contract.update_config(config), UpdateConfigResult::Success);
+
+    register_protocol_and_request_with_bounty(&mut contract, BOUNTY);
+
+    let miners = generate_miners(10);
+    register_and_commit_miners(&mut contract, &miners, 0);
+    reveal_miners(&mut contract, &miners, 0);
+
+    let validator = generate_validators(1)[0].clone();
+    register_and_commit_validator(&mut contract, &validator, &miners);
+    reveal_validator(&mut contract, &validator, &miners);
+
+    Environment::with_account(validator.clone()).with_block_timestamp(REVEAL_TOPTEN_TIME).create();
+    contract.finalize_request(DEFAULT_REQUEST_ID.to_string());
+
+    // @dev Only the base reward is split: 1 near 70% / 30%
+    assert_transfers(vec![(get_default_protocol_account(), BOUNTY)]);
+    assert_eq!(contract.get_pending_rewards(validator), NearToken::from_millinear(300));
+}
@@ -24,7 +24,7 @@ fn test_commit_by_miner_when_miner_and_request_exist() {
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
     contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -69,7 +69,7 @@ fn test_commit_by_miner_when_miner_dont_registered_and_request_exist() {
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
     contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -125,7 +125,7 @@ fn test_commit_by_miner_when_miner_registered_and_request_dont_exist() {
             event_name: "register_miner".to_string(),
             data: vec![("miner", json![MINER_1])],
         },
-        Log::Message("Request is not registered: 73ead60176d724e462dbfa8d49506177bb13bec748cf5af5019b6d1da63e204b".to_string()),
+        Log::Message("Request is not registered: 1b7b4a1997b364a89227157bb5e816f311616fdbf6206195c68a7c95bf1c6f43".to_string()),
     ]);
 }
 
@@ -141,7 +141,7 @@ fn test_commit_by_miner_when_miner_and_request_exist_and_commit_already() {
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
     contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
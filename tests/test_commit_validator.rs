@@ -24,7 +24,7 @@ fn test_commit_by_validator_when_validator_and_request_exist() {
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
     contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None);
 
     assert_logs(vec![
         Log::Event {
@@ -79,7 +79,7 @@ fn test_commit_by_validator_when_validator_dont_registered_and_request_exist() {
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
     contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None);
 
     assert_logs(vec![
         Log::Event {
@@ -117,7 +117,7 @@ fn test_commit_by_validator_when_validator_registered_and_request_dont_exist() {
             event_name: "register_validator".to_string(),
             data: vec![("validator", json![VALIDATOR_1])],
         },
-        Log::Message("Request is not registered: 73ead60176d724e462dbfa8d49506177bb13bec748cf5af5019b6d1da63e204b".to_string()),
+        Log::Message("Request is not registered: 1b7b4a1997b364a89227157bb5e816f311616fdbf6206195c68a7c95bf1c6f43".to_string()),
     ]);
 }
 
@@ -133,7 +133,7 @@ fn test_commit_by_validator_when_validator_and_request_exist_and_commit_already(
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
     contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None);
 
     assert_logs(vec![
         Log::Event {
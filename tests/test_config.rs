@@ -0,0 +1,131 @@
+use near_sdk::NearToken;
+use serde_json::json;
+
+use common::constants::{DEFAULT_CULTURE, DEFAULT_DEPOSIT_MINER, DEFAULT_DEPOSIT_PROTOCOL, OWNER};
+use common::environment::Environment;
+use common::types::Log;
+use common::utils::{assert_logs, get_default_miner_account, get_default_protocol_account};
+
+use earthmind_rs::{Config, Contract, Module, UpdateConfigResult, DEFAULT_CONFIG};
+
+pub mod common;
+
+fn setup_contract() -> Contract {
+    Environment::with_account(OWNER.parse().unwrap()).create();
+    Contract::new()
+}
+
+const UPDATED_CONFIG: Config = Config {
+    min_miner_stake: NearToken::from_near(2),
+    protocol_registration_fee: NearToken::from_near(10),
+    ..DEFAULT_CONFIG
+};
+
+#[test]
+fn test_get_config_returns_the_defaults() {
+    let contract = setup_contract();
+
+    assert_eq!(contract.get_config(), DEFAULT_CONFIG);
+}
+
+#[test]
+fn test_update_config() {
+    let mut contract = setup_contract();
+
+    Environment::with_account(OWNER.parse().unwrap()).create();
+    let result = contract.update_config(UPDATED_CONFIG);
+
+    assert_eq!(result, UpdateConfigResult::Success);
+    assert_eq!(contract.get_config(), UPDATED_CONFIG);
+    assert_logs(vec![Log::Event {
+        event_name: "config_updated".to_string(),
+        data: vec![("account", json![OWNER]), ("config", json![UPDATED_CONFIG])],
+    }]);
+}
+
+#[test]
+#[should_panic(expected = "Only an account with one of the roles [Owner, Admin] can call this method")]
+fn test_update_config_when_caller_is_not_an_admin() {
+    let mut contract = setup_contract();
+
+    Environment::with_account(get_default_protocol_account()).create();
+    contract.update_config(UPDATED_CONFIG);
+}
+
+#[test]
+fn test_update_config_when_config_is_invalid() {
+    let mut contract = setup_contract();
+
+    Environment::with_account(OWNER.parse().unwrap()).create();
+
+    let zero_stake = Config {
+        min_validator_stake: NearToken::from_yoctonear(0),
+        ..DEFAULT_CONFIG
+    };
+    let slash_of_whole_stake = Config {
+        validator_slash_percentage: 100,
+        ..DEFAULT_CONFIG
+    };
+    let bounty_without_validators = Config {
+        bounty_min_revealed_validators: 0,
+        ..DEFAULT_CONFIG
+    };
+    let deviation_over_ranking = Config {
+        validator_deviation_threshold: 11,
+        ..DEFAULT_CONFIG
+    };
+    let threshold_under_majority = Config {
+        decision_threshold_percentage: 49,
+        ..DEFAULT_CONFIG
+    };
+    let threshold_over_total = Config {
+        decision_threshold_percentage: 101,
+        ..DEFAULT_CONFIG
+    };
+    let zero_weight_cap = Config {
+        vote_weight_cap: 0,
+        ..DEFAULT_CONFIG
+    };
+    let trimmed_whole_ranking = Config {
+        trimmed_percentage: 50,
+        ..DEFAULT_CONFIG
+    };
+
+    assert_eq!(contract.update_config(zero_stake), UpdateConfigResult::InvalidConfig);
+    assert_eq!(contract.update_config(slash_of_whole_stake), UpdateConfigResult::InvalidConfig);
+    assert_eq!(contract.update_config(bounty_without_validators), UpdateConfigResult::InvalidConfig);
+    assert_eq!(contract.update_config(deviation_over_ranking), UpdateConfigResult::InvalidConfig);
+    assert_eq!(contract.update_config(threshold_under_majority), UpdateConfigResult::InvalidConfig);
+    assert_eq!(contract.update_config(threshold_over_total), UpdateConfigResult::InvalidConfig);
+    assert_eq!(contract.update_config(zero_weight_cap), UpdateConfigResult::InvalidConfig);
+    assert_eq!(contract.update_config(trimmed_whole_ranking), UpdateConfigResult::InvalidConfig);
+    assert_eq!(contract.get_config(), DEFAULT_CONFIG);
+}
+
+#[test]
+#[should_panic(expected = "Deposit is less than the required to register")]
+fn test_register_protocol_uses_the_updated_fee() {
+    let mut contract = setup_contract();
+
+    Environment::with_account(OWNER.parse().unwrap()).create();
+    contract.update_config(UPDATED_CONFIG);
+
+    Environment::with_account(get_default_protocol_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+    contract.register_protocol(DEFAULT_CULTURE.to_string(), vec![Module::TextPrompting]);
+}
+
+#[test]
+#[should_panic(expected = "Miner deposit is less than the minimum stake")]
+fn test_register_miner_uses_the_updated_stake() {
+    let mut contract = setup_contract();
+
+    Environment::with_account(OWNER.parse().unwrap()).create();
+    contract.update_config(UPDATED_CONFIG);
+
+    Environment::with_account(get_default_miner_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_MINER)
+        .create();
+    contract.register_miner();
+}
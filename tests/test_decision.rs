@@ -0,0 +1,154 @@
+use common::constants::{DEFAULT_REQUEST_ID, REVEAL_TOPTEN_TIME};
+use common::environment::Environment;
+use common::lifecycle::{
+    generate_miners, generate_validators, register_and_commit_miners, register_and_commit_validator, register_protocol_and_request,
+    register_protocol_and_request_with_options, reveal_miners, reveal_validator,
+};
+use common::types::Log;
+use common::utils::assert_logs;
+
+use earthmind_rs::{Config, Contract, Decision, FinalizeResult, UpdateConfigResult, DEFAULT_CONFIG};
+
+pub mod common;
+
+// @dev Every miner answers the option at its position in `answers`
+fn setup_request(options: Option<&[&str]>, answers: [u32; 10]) -> Contract {
+    let mut contract = Contract::new();
+
+    match options {
+        Some(options) => register_protocol_and_request_with_options(&mut contract, options),
+        None => register_protocol_and_request(&mut contract),
+    }
+
+    let miners = generate_miners(10);
+
+    for (miner, answer) in miners.iter().zip(answers) {
+        register_and_commit_miners(&mut contract, std::slice::from_ref(miner), answer);
+    }
+
+    for (miner, answer) in miners.iter().zip(answers) {
+        reveal_miners(&mut contract, std::slice::from_ref(miner), answer);
+    }
+
+    let validator = generate_validators(1)[0].clone();
+    register_and_commit_validator(&mut contract, &validator, &miners);
+    reveal_validator(&mut contract, &validator, &miners);
+
+    Environment::with_account(validator).with_block_timestamp(REVEAL_TOPTEN_TIME).create();
+
+    contract
+}
+
+#[test]
+fn test_decision_picks_the_option_above_threshold() {
+    let mut contract = setup_request(None, [0, 0, 0, 0, 0, 0, 1, 1, 1, 1]);
+
+    assert!(contract.get_decision(DEFAULT_REQUEST_ID.to_string()).is_none());
+
+    contract.finalize_request(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(
+        contract.get_decision(DEFAULT_REQUEST_ID.to_string()),
+        Some(Decision {
+            option: Some(0),
+            votes: vec![6, 4],
+            value: None,
+        })
+    );
+}
+
+#[test]
+fn test_decision_has_no_option_at_threshold() {
+    let mut contract = setup_request(None, [0, 0, 0, 0, 0, 1, 1, 1, 1, 1]);
+
+    contract.finalize_request(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(
+        contract.get_decision(DEFAULT_REQUEST_ID.to_string()),
+        Some(Decision {
+            option: None,
+            votes: vec![5, 5],
+            value: None,
+        })
+    );
+}
+
+#[test]
+fn test_decision_uses_the_configured_threshold() {
+    let mut contract = setup_request(None, [0, 0, 0, 0, 0, 0, 1, 1, 1, 1]);
+
+    Environment::with_account(contract.get_owner())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+    let config = Config {
+        decision_threshold_percentage: 60,
+        ..DEFAULT_CONFIG
+    };
+    assert_eq!(contract.update_config(config), UpdateConfigResult::Success);
+
+    contract.finalize_request(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(
+        contract.get_decision(DEFAULT_REQUEST_ID.to_string()),
+        Some(Decision {
+            option: None,
+            votes: vec![6, 4],
+            value: None,
+        })
+    );
+}
+
+#[test]
+fn test_decision_with_multiple_options() {
+    let mut contract = setup_request(Some(&["Low", "Medium", "High"]), [2, 2, 0, 2, 2, 0, 1, 2, 2, 0]);
+
+    contract.finalize_request(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(
+        contract.get_decision(DEFAULT_REQUEST_ID.to_string()),
+        Some(Decision {
+            option: Some(2),
+            votes: vec![3, 1, 6],
+            value: None,
+        })
+    );
+}
+
+#[test]
+fn test_decision_with_multiple_options_and_no_majority() {
+    let mut contract = setup_request(Some(&["Low", "Medium", "High"]), [0, 0, 0, 0, 1, 1, 1, 1, 2, 2]);
+
+    contract.finalize_request(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(
+        contract.get_decision(DEFAULT_REQUEST_ID.to_string()),
+        Some(Decision {
+            option: None,
+            votes: vec![4, 4, 2],
+            value: None,
+        })
+    );
+}
+
+#[test]
+fn test_decision_is_finalized_only_once() {
+    let mut contract = setup_request(None, [0; 10]);
+
+    assert_eq!(contract.finalize_request(DEFAULT_REQUEST_ID.to_string()), FinalizeResult::Success);
+
+    Environment::with_account(generate_validators(1)[0].clone())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+
+    let result = contract.finalize_request(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(result, FinalizeResult::AlreadyFinalized);
+    assert_logs(vec![Log::Message(format!("Request already finalized: {}", DEFAULT_REQUEST_ID))]);
+}
+
+#[test]
+fn test_get_decision_when_request_is_not_registered() {
+    let contract = Contract::new();
+
+    assert!(contract.get_decision(DEFAULT_REQUEST_ID.to_string()).is_none());
+}
@@ -0,0 +1,138 @@
+use near_sdk::{AccountId, PromiseResult};
+use serde_json::json;
+
+use common::constants::{CONTRACT_ACCOUNT_ID, DEFAULT_REQUEST_ID, REVEAL_TOPTEN_TIME};
+use common::environment::Environment;
+use common::lifecycle::{
+    generate_miners, generate_validators, register_and_commit_miners, register_and_commit_validator, register_protocol_and_request, reveal_miners,
+    reveal_validator,
+};
+use common::types::Log;
+use common::utils::{assert_function_calls, assert_logs, get_default_protocol_account};
+
+use earthmind_rs::{Contract, DecisionCallbackStatus, RetryCallbackResult};
+
+pub mod common;
+
+fn get_contract_account() -> AccountId {
+    CONTRACT_ACCOUNT_ID.parse().unwrap()
+}
+
+fn setup_finalized_request() -> Contract {
+    let mut contract = Contract::new();
+    register_protocol_and_request(&mut contract);
+
+    let miners = generate_miners(10);
+    register_and_commit_miners(&mut contract, &miners, 0);
+    reveal_miners(&mut contract, &miners, 0);
+
+    let validator = generate_validators(1)[0].clone();
+    register_and_commit_validator(&mut contract, &validator, &miners);
+    reveal_validator(&mut contract, &validator, &miners);
+
+    Environment::with_account(validator).with_block_timestamp(REVEAL_TOPTEN_TIME).create();
+    contract.finalize_request(DEFAULT_REQUEST_ID.to_string());
+
+    contract
+}
+
+fn resolve_callback(contract: &mut Contract, result: PromiseResult) -> bool {
+    Environment::with_account(get_contract_account())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .with_promise_results(vec![result])
+        .create();
+
+    contract.on_decision_delivered(DEFAULT_REQUEST_ID.to_string())
+}
+
+#[test]
+fn test_finalization_calls_back_the_protocol() {
+    let contract = setup_finalized_request();
+
+    assert_function_calls(vec![
+        (get_default_protocol_account(), "on_earthmind_decision"),
+        (get_contract_account(), "on_decision_delivered"),
+    ]);
+    assert_eq!(
+        contract.get_decision_callback_status(DEFAULT_REQUEST_ID.to_string()),
+        Some(DecisionCallbackStatus::Pending)
+    );
+}
+
+#[test]
+fn test_on_decision_delivered_when_callback_succeeds() {
+    let mut contract = setup_finalized_request();
+
+    let result = resolve_callback(&mut contract, PromiseResult::Successful(vec![]));
+
+    assert!(result);
+    assert_eq!(
+        contract.get_decision_callback_status(DEFAULT_REQUEST_ID.to_string()),
+        Some(DecisionCallbackStatus::Delivered)
+    );
+    assert_logs(vec![]);
+}
+
+#[test]
+fn test_on_decision_delivered_when_callback_fails() {
+    let mut contract = setup_finalized_request();
+
+    let result = resolve_callback(&mut contract, PromiseResult::Failed);
+
+    assert!(!result);
+    assert_eq!(
+        contract.get_decision_callback_status(DEFAULT_REQUEST_ID.to_string()),
+        Some(DecisionCallbackStatus::Failed)
+    );
+    assert_logs(vec![Log::Event {
+        event_name: "decision_callback_failed".to_string(),
+        data: vec![("request_id", json![DEFAULT_REQUEST_ID]), ("protocol", json![get_default_protocol_account()])],
+    }]);
+}
+
+#[test]
+fn test_retry_decision_callback_after_failure() {
+    let mut contract = setup_finalized_request();
+    resolve_callback(&mut contract, PromiseResult::Failed);
+
+    Environment::with_account(get_default_protocol_account()).create();
+
+    let result = contract.retry_decision_callback(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(result, RetryCallbackResult::Success);
+    assert_function_calls(vec![
+        (get_default_protocol_account(), "on_earthmind_decision"),
+        (get_contract_account(), "on_decision_delivered"),
+    ]);
+    assert_eq!(
+        contract.get_decision_callback_status(DEFAULT_REQUEST_ID.to_string()),
+        Some(DecisionCallbackStatus::Pending)
+    );
+}
+
+#[test]
+fn test_retry_decision_callback_when_callback_has_not_failed() {
+    let mut contract = setup_finalized_request();
+
+    Environment::with_account(get_default_protocol_account()).create();
+
+    let result = contract.retry_decision_callback(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(result, RetryCallbackResult::NotFailed);
+    assert_function_calls(vec![]);
+    assert_logs(vec![Log::Message(format!(
+        "Decision callback has not failed for request: {}",
+        DEFAULT_REQUEST_ID
+    ))]);
+}
+
+#[test]
+fn test_retry_decision_callback_when_request_is_not_registered() {
+    let mut contract = Contract::new();
+
+    Environment::with_account(get_default_protocol_account()).create();
+
+    let result = contract.retry_decision_callback(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(result, RetryCallbackResult::Fail);
+}
@@ -1,7 +1,8 @@
 use earthmind_rs::{
-    CommitMinerLog, CommitValidatorLog, EventLog, EventLogVariant, RegisterMinerLog, RegisterProtocolLog, RegisterRequestLog, RegisterValidatorLog,
-    RevealMinerLog, RevealValidatorLog, ToptenMinersLog,
+    AnswerPayload, CommitMinerLog, CommitValidatorLog, EventLog, EventLogVariant, RegisterMinerLog, RegisterProtocolLog, RegisterRequestLog,
+    RegisterValidatorLog, RevealMinerLog, RevealValidatorLog, ToptenMinersLog, UnstakeMinerLog, WithdrawValidatorLog,
 };
+use near_sdk::NearToken;
 
 #[test]
 fn test_format_register_protocol() {
@@ -108,14 +109,35 @@ fn test_format_commit_validator() {
 
 #[test]
 fn test_format_reveal_miner() {
-    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"1.0.0","event":"reveal_miner","data":[{"request_id":"0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726","answer":true,"message":"It's a cool NFT"}]}"#;
+    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"reveal_miner","data":[{"request_id":"0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726","answer":0,"message":"It's a cool NFT"}]}"#;
     let log = EventLog {
         standard: "emip001".to_string(),
-        version: "1.0.0".to_string(),
+        version: "2.0.0".to_string(),
         event: EventLogVariant::RevealMiner(vec![RevealMinerLog {
             request_id: "0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726".to_string(),
-            answer: true,
-            message: "It's a cool NFT".to_string(),
+            answer: 0,
+            message: Some("It's a cool NFT".to_string()),
+            payload: None,
+        }]),
+    };
+
+    assert_eq!(expected, log.to_string());
+}
+
+#[test]
+fn test_format_reveal_miner_with_payload() {
+    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"2.0.0","event":"reveal_miner","data":[{"request_id":"0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726","answer":0,"payload":{"content_hash":"3910deb8f11de66388bddcc1eb1bf1e33319b71a18df2c1019e6d72c6d00f464","uri":"ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"}}]}"#;
+    let log = EventLog {
+        standard: "emip001".to_string(),
+        version: "2.0.0".to_string(),
+        event: EventLogVariant::RevealMiner(vec![RevealMinerLog {
+            request_id: "0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae726".to_string(),
+            answer: 0,
+            message: None,
+            payload: Some(AnswerPayload {
+                content_hash: "3910deb8f11de66388bddcc1eb1bf1e33319b71a18df2c1019e6d72c6d00f464".to_string(),
+                uri: "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_string(),
+            }),
         }]),
     };
 
@@ -173,3 +195,32 @@ fn test_format_topten_miners() {
     };
     assert_eq!(expected, log.to_string());
 }
+
+#[test]
+fn test_format_unstake_miner() {
+    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"1.0.0","event":"unstake_miner","data":[{"miner":"miner1.near","amount":"1000000000000000000000000","unlock_time":86500000000000}]}"#;
+    let log = EventLog {
+        standard: "emip001".to_string(),
+        version: "1.0.0".to_string(),
+        event: EventLogVariant::UnstakeMiner(vec![UnstakeMinerLog {
+            miner: "miner1.near".parse().unwrap(),
+            amount: NearToken::from_near(1),
+            unlock_time: 86_500_000_000_000,
+        }]),
+    };
+    assert_eq!(expected, log.to_string());
+}
+
+#[test]
+fn test_format_withdraw_validator() {
+    let expected = r#"EVENT_JSON:{"standard":"emip001","version":"1.0.0","event":"withdraw_validator","data":[{"validator":"validator1.near","amount":"10000000000000000000000000"}]}"#;
+    let log = EventLog {
+        standard: "emip001".to_string(),
+        version: "1.0.0".to_string(),
+        event: EventLogVariant::WithdrawValidator(vec![WithdrawValidatorLog {
+            validator: "validator1.near".parse().unwrap(),
+            amount: NearToken::from_near(10),
+        }]),
+    };
+    assert_eq!(expected, log.to_string());
+}
@@ -0,0 +1,133 @@
+use common::constants::{DEFAULT_REQUEST_ID, REVEAL_TOPTEN_TIME, REVEAL_VALIDATOR_TIME};
+use common::environment::Environment;
+use common::lifecycle::{
+    generate_miners, generate_validators, register_and_commit_miners, register_and_commit_validator, register_protocol_and_request, reveal_miners,
+    reveal_validator,
+};
+use common::types::Log;
+use common::utils::assert_logs;
+
+use earthmind_rs::{Contract, Decision, FinalizeResult, RequestResults};
+
+pub mod common;
+
+fn setup_revealed_request() -> Contract {
+    let mut contract = Contract::new();
+    register_protocol_and_request(&mut contract);
+
+    let miners = generate_miners(10);
+    register_and_commit_miners(&mut contract, &miners, 0);
+    reveal_miners(&mut contract, &miners, 0);
+
+    let validator = generate_validators(1)[0].clone();
+    register_and_commit_validator(&mut contract, &validator, &miners);
+    reveal_validator(&mut contract, &validator, &miners);
+
+    contract
+}
+
+#[test]
+fn test_finalize_request() {
+    let mut contract = setup_revealed_request();
+
+    Environment::with_account(generate_validators(1)[0].clone())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+
+    let result = contract.finalize_request(DEFAULT_REQUEST_ID.to_string());
+
+    let top_ten: Vec<_> = generate_miners(10).into_iter().zip((1..=10).rev()).collect();
+
+    assert_eq!(result, FinalizeResult::Success);
+    assert_eq!(contract.get_top_miners(DEFAULT_REQUEST_ID.to_string()), Some(top_ten.clone()));
+    assert_eq!(
+        contract.get_results(DEFAULT_REQUEST_ID.to_string()),
+        Some(RequestResults {
+            request_id: DEFAULT_REQUEST_ID.to_string(),
+            top_ten,
+            decision: Some(Decision {
+                option: Some(0),
+                votes: vec![10, 0],
+                value: None,
+            }),
+        })
+    );
+}
+
+#[test]
+fn test_results_are_not_available_before_finalization() {
+    let contract = setup_revealed_request();
+
+    let miner = generate_miners(1)[0].clone();
+
+    assert_eq!(contract.get_votes_for_miner(DEFAULT_REQUEST_ID.to_string(), miner), Some(10));
+    assert!(contract.get_top_miners(DEFAULT_REQUEST_ID.to_string()).is_none());
+    assert!(contract.get_results(DEFAULT_REQUEST_ID.to_string()).is_none());
+}
+
+#[test]
+fn test_get_votes_for_miner_when_miner_has_no_votes() {
+    let contract = setup_revealed_request();
+
+    let result = contract.get_votes_for_miner(DEFAULT_REQUEST_ID.to_string(), "miner11.near".parse().unwrap());
+
+    assert!(result.is_none());
+}
+
+#[test]
+fn test_get_top_10_voters_reads_the_stored_top_ten() {
+    let mut contract = setup_revealed_request();
+
+    Environment::with_account(generate_validators(1)[0].clone())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+    contract.finalize_request(DEFAULT_REQUEST_ID.to_string());
+
+    Environment::with_account(generate_validators(1)[0].clone())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+
+    let top_ten = contract.get_top_10_voters(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(Some(top_ten), contract.get_top_miners(DEFAULT_REQUEST_ID.to_string()));
+    assert_logs(vec![]);
+}
+
+#[test]
+fn test_get_top_10_voters_does_not_finalize_the_request() {
+    let contract = setup_revealed_request();
+
+    Environment::with_account(generate_validators(1)[0].clone())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+
+    assert!(contract.get_top_10_voters(DEFAULT_REQUEST_ID.to_string()).is_empty());
+    assert!(contract.get_results(DEFAULT_REQUEST_ID.to_string()).is_none());
+    assert_logs(vec![]);
+}
+
+#[test]
+#[should_panic(expected = "Not stage ended")]
+fn test_finalize_request_when_stage_has_not_ended() {
+    let mut contract = setup_revealed_request();
+
+    Environment::with_account(generate_validators(1)[0].clone())
+        .with_block_timestamp(REVEAL_VALIDATOR_TIME)
+        .create();
+
+    contract.finalize_request(DEFAULT_REQUEST_ID.to_string());
+}
+
+#[test]
+fn test_finalize_request_when_request_is_not_registered() {
+    let mut contract = Contract::new();
+
+    Environment::with_account(generate_validators(1)[0].clone())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+
+    let result = contract.finalize_request(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(result, FinalizeResult::Fail);
+    assert_logs(vec![Log::Message(format!("Request is not registered: {}", DEFAULT_REQUEST_ID))]);
+}
@@ -0,0 +1,111 @@
+use near_sdk::NearToken;
+
+use common::constants::{DEFAULT_REQUEST_ID, DEFAULT_TIMESTAMP, REVEAL_TOPTEN_TIME};
+use common::environment::Environment;
+use common::lifecycle::{
+    generate_miners, generate_validators, register_and_commit_miners, register_and_commit_validator, register_protocol_and_request, reveal_miners,
+    reveal_validator,
+};
+use common::utils::get_default_protocol_account;
+
+use earthmind_rs::{Contract, Decision, RequestState, RequestView, TWO_MINUTES};
+
+pub mod common;
+
+#[test]
+fn test_get_request_when_request_is_registered() {
+    let mut contract = Contract::new();
+    register_protocol_and_request(&mut contract);
+
+    let request = contract.get_request(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(
+        request,
+        Some(RequestView {
+            request_id: DEFAULT_REQUEST_ID.to_string(),
+            sender: get_default_protocol_account(),
+            start_time: DEFAULT_TIMESTAMP,
+            stage: RequestState::CommitMiners,
+            commit_miners_deadline: DEFAULT_TIMESTAMP + TWO_MINUTES,
+            reveal_miners_deadline: DEFAULT_TIMESTAMP + 2 * TWO_MINUTES,
+            commit_validators_deadline: DEFAULT_TIMESTAMP + 3 * TWO_MINUTES,
+            reveal_validators_deadline: DEFAULT_TIMESTAMP + 4 * TWO_MINUTES,
+            miners_committed: 0,
+            miners_revealed: 0,
+            validators_committed: 0,
+            validators_revealed: 0,
+            bounty: NearToken::from_yoctonear(0),
+            options: vec!["Yes".to_string(), "No".to_string()],
+            numeric: None,
+            top_ten: None,
+            decision: None,
+        })
+    );
+}
+
+#[test]
+fn test_get_request_when_request_is_finalized() {
+    let mut contract = Contract::new();
+    register_protocol_and_request(&mut contract);
+
+    let miners = generate_miners(11);
+    register_and_commit_miners(&mut contract, &miners, 0);
+    reveal_miners(&mut contract, &miners[..10], 0);
+
+    let validators = generate_validators(2);
+    register_and_commit_validator(&mut contract, &validators[0], &miners[..10]);
+    register_and_commit_validator(&mut contract, &validators[1], &miners[..10]);
+    reveal_validator(&mut contract, &validators[0], &miners[..10]);
+
+    Environment::with_account(validators[0].clone())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+    contract.finalize_request(DEFAULT_REQUEST_ID.to_string());
+    let top_ten = contract.get_top_10_voters(DEFAULT_REQUEST_ID.to_string());
+
+    let request = contract.get_request(DEFAULT_REQUEST_ID.to_string()).unwrap();
+
+    assert_eq!(request.stage, RequestState::Ended);
+    assert_eq!(request.miners_committed, 11);
+    assert_eq!(request.miners_revealed, 10);
+    assert_eq!(request.validators_committed, 2);
+    assert_eq!(request.validators_revealed, 1);
+    assert_eq!(request.top_ten, Some(top_ten));
+    assert_eq!(
+        request.decision,
+        Some(Decision {
+            option: Some(0),
+            votes: vec![10, 0],
+            value: None,
+        })
+    );
+}
+
+#[test]
+fn test_get_request_counts_revealed_validators() {
+    let mut contract = Contract::new();
+    register_protocol_and_request(&mut contract);
+
+    let miners = generate_miners(10);
+    register_and_commit_miners(&mut contract, &miners, 0);
+    reveal_miners(&mut contract, &miners, 0);
+
+    let validators = generate_validators(2);
+    register_and_commit_validator(&mut contract, &validators[0], &miners);
+    register_and_commit_validator(&mut contract, &validators[1], &miners);
+    reveal_validator(&mut contract, &validators[0], &miners);
+
+    let request = contract.get_request(DEFAULT_REQUEST_ID.to_string()).unwrap();
+
+    assert_eq!(request.stage, RequestState::RevealValidators);
+    assert_eq!(request.validators_committed, 2);
+    assert_eq!(request.validators_revealed, 1);
+    assert_eq!(request.top_ten, None);
+}
+
+#[test]
+fn test_get_request_when_request_is_not_registered() {
+    let contract = Contract::new();
+
+    assert!(contract.get_request(DEFAULT_REQUEST_ID.to_string()).is_none());
+}
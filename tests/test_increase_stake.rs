@@ -0,0 +1,170 @@
+use near_sdk::NearToken;
+use serde_json::json;
+
+use common::constants::{DEFAULT_DEPOSIT_MINER, DEFAULT_DEPOSIT_VALIDATOR, MINER_1, VALIDATOR_1};
+use common::environment::Environment;
+use common::types::Log;
+use common::utils::{assert_logs, assert_transfers, get_default_miner_account, get_default_validator_account};
+
+use earthmind_rs::{Contract, IncreaseStakeResult};
+
+pub mod common;
+
+#[test]
+fn test_increase_miner_stake() {
+    let mut contract = Contract::new();
+
+    let miner = get_default_miner_account();
+    Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+    contract.register_miner();
+
+    let result = contract.increase_miner_stake();
+
+    assert_eq!(result, IncreaseStakeResult::Success);
+    assert_eq!(contract.get_miner_stake(miner), Some(NearToken::from_near(2)));
+
+    assert_logs(vec![
+        Log::Event {
+            event_name: "register_miner".to_string(),
+            data: vec![("miner", json![MINER_1])],
+        },
+        Log::Event {
+            event_name: "increase_miner_stake".to_string(),
+            data: vec![
+                ("miner", json![MINER_1]),
+                ("amount", json![DEFAULT_DEPOSIT_MINER]),
+                ("stake", json![NearToken::from_near(2)]),
+            ],
+        },
+    ]);
+    assert_transfers(vec![]);
+}
+
+#[test]
+fn test_increase_validator_stake() {
+    let mut contract = Contract::new();
+
+    let validator = get_default_validator_account();
+    Environment::with_account(validator.clone())
+        .with_attached_deposit(DEFAULT_DEPOSIT_VALIDATOR)
+        .create();
+    contract.register_validator();
+
+    Environment::with_account(validator.clone())
+        .with_attached_deposit(NearToken::from_near(5))
+        .create();
+
+    let result = contract.increase_validator_stake();
+
+    assert_eq!(result, IncreaseStakeResult::Success);
+    assert_eq!(contract.get_validator_stake(validator), Some(NearToken::from_near(15)));
+
+    assert_logs(vec![Log::Event {
+        event_name: "increase_validator_stake".to_string(),
+        data: vec![
+            ("validator", json![VALIDATOR_1]),
+            ("amount", json![NearToken::from_near(5)]),
+            ("stake", json![NearToken::from_near(15)]),
+        ],
+    }]);
+}
+
+#[test]
+fn test_increase_miner_stake_when_miner_is_not_registered_refunds_deposit() {
+    let mut contract = Contract::new();
+
+    let miner = get_default_miner_account();
+    Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+
+    let result = contract.increase_miner_stake();
+
+    assert_eq!(result, IncreaseStakeResult::NotRegistered);
+    assert!(contract.get_miner_stake(miner.clone()).is_none());
+
+    assert_logs(vec![Log::Message("Miner not registered: miner1.near".to_string())]);
+    assert_transfers(vec![(miner, DEFAULT_DEPOSIT_MINER)]);
+}
+
+#[test]
+#[should_panic(expected = "Deposit must be greater than zero")]
+fn test_increase_validator_stake_without_deposit() {
+    let mut contract = Contract::new();
+
+    let validator = get_default_validator_account();
+    Environment::with_account(validator.clone())
+        .with_attached_deposit(DEFAULT_DEPOSIT_VALIDATOR)
+        .create();
+    contract.register_validator();
+
+    Environment::with_account(validator)
+        .with_attached_deposit(NearToken::from_yoctonear(0))
+        .create();
+
+    contract.increase_validator_stake();
+}
+
+#[test]
+fn test_increase_stake_of_a_miner() {
+    let mut contract = Contract::new();
+
+    let miner = get_default_miner_account();
+    Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+    contract.register_miner();
+
+    let result = contract.increase_stake();
+
+    assert_eq!(result, IncreaseStakeResult::Success);
+    assert_eq!(contract.get_miner_stake(miner), Some(NearToken::from_near(2)));
+}
+
+#[test]
+fn test_increase_stake_of_a_validator() {
+    let mut contract = Contract::new();
+
+    let validator = get_default_validator_account();
+    Environment::with_account(validator.clone())
+        .with_attached_deposit(DEFAULT_DEPOSIT_VALIDATOR)
+        .create();
+    contract.register_validator();
+
+    Environment::with_account(validator.clone())
+        .with_attached_deposit(NearToken::from_near(5))
+        .create();
+
+    let result = contract.increase_stake();
+
+    assert_eq!(result, IncreaseStakeResult::Success);
+    assert_eq!(contract.get_validator_stake(validator), Some(NearToken::from_near(15)));
+}
+
+#[test]
+fn test_increase_stake_when_account_is_not_registered_refunds_deposit() {
+    let mut contract = Contract::new();
+
+    let miner = get_default_miner_account();
+    Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+
+    let result = contract.increase_stake();
+
+    assert_eq!(result, IncreaseStakeResult::NotRegistered);
+    assert_logs(vec![Log::Message("Account is not registered: miner1.near".to_string())]);
+    assert_transfers(vec![(miner, DEFAULT_DEPOSIT_MINER)]);
+}
+
+#[test]
+#[should_panic(expected = "Account is both a miner and a validator, use increase_miner_stake or increase_validator_stake")]
+fn test_increase_stake_when_account_is_a_miner_and_a_validator() {
+    let mut contract = Contract::new();
+
+    let account = get_default_miner_account();
+    Environment::with_account(account.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+    contract.register_miner();
+
+    Environment::with_account(account.clone())
+        .with_attached_deposit(DEFAULT_DEPOSIT_VALIDATOR)
+        .create();
+    contract.register_validator();
+
+    Environment::with_account(account).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+    contract.increase_stake();
+}
@@ -0,0 +1,255 @@
+use near_sdk::json_types::I128;
+use serde_json::json;
+
+use common::constants::{DEFAULT_DEPOSIT_MINER, DEFAULT_REQUEST_ID, DEFAULT_REVEAL_MESSAGE, REVEAL_MINER_TIME, REVEAL_TOPTEN_TIME};
+use common::environment::Environment;
+use common::lifecycle::{
+    generate_miners, generate_validators, hash_miner_value, register_and_commit_miners, register_and_commit_miners_value, register_and_commit_validator,
+    register_protocol_and_numeric_request, register_protocol_and_request, reveal_miners_value, reveal_validator,
+};
+use common::types::Log;
+use common::utils::{assert_logs, get_default_miner_account};
+
+use earthmind_rs::{Aggregation, AnswerPayload, Config, Contract, Decision, NumericAnswer, RevealMinerResult, UpdateConfigResult, DEFAULT_CONFIG};
+
+pub mod common;
+
+const FEE_ANSWER: NumericAnswer = NumericAnswer {
+    min: I128(0),
+    max: I128(10_000),
+    decimals: 2,
+    aggregation: Aggregation::Median,
+};
+
+// @dev Every miner reveals the value at its position in `values`
+fn setup_request(aggregation: Aggregation, values: &[i128]) -> Contract {
+    setup_request_with_config(aggregation, values, DEFAULT_CONFIG)
+}
+
+fn setup_request_with_config(aggregation: Aggregation, values: &[i128], config: Config) -> Contract {
+    let mut contract = Contract::new();
+
+    Environment::with_account(contract.get_owner()).create();
+    assert_eq!(contract.update_config(config), UpdateConfigResult::Success);
+
+    register_protocol_and_numeric_request(&mut contract, NumericAnswer { aggregation, ..FEE_ANSWER });
+
+    let miners = generate_miners(10);
+
+    for (miner, value) in miners.iter().zip(values) {
+        register_and_commit_miners_value(&mut contract, std::slice::from_ref(miner), *value);
+    }
+
+    for (miner, value) in miners.iter().zip(values) {
+        reveal_miners_value(&mut contract, std::slice::from_ref(miner), *value);
+    }
+
+    let validator = generate_validators(1)[0].clone();
+    register_and_commit_validator(&mut contract, &validator, &miners);
+    reveal_validator(&mut contract, &validator, &miners);
+
+    Environment::with_account(validator).with_block_timestamp(REVEAL_TOPTEN_TIME).create();
+    contract.finalize_request(DEFAULT_REQUEST_ID.to_string());
+
+    contract
+}
+
+fn numeric_decision(value: Option<i128>) -> Option<Decision> {
+    Some(Decision {
+        option: None,
+        votes: Vec::new(),
+        value: value.map(I128),
+    })
+}
+
+#[test]
+fn test_request_numeric_decision() {
+    let mut contract = Contract::new();
+    register_protocol_and_numeric_request(&mut contract, FEE_ANSWER);
+
+    let request = contract.get_request(DEFAULT_REQUEST_ID.to_string()).unwrap();
+
+    assert_eq!(request.numeric, Some(FEE_ANSWER));
+    assert!(request.options.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "The minimum must be lower than the maximum")]
+fn test_request_numeric_decision_when_bounds_are_inverted() {
+    let mut contract = Contract::new();
+    register_protocol_and_numeric_request(
+        &mut contract,
+        NumericAnswer {
+            min: I128(10),
+            max: I128(10),
+            ..FEE_ANSWER
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Bounds must be between -1000000000000000000000000000000000000 and 1000000000000000000000000000000000000")]
+fn test_request_numeric_decision_when_bounds_are_too_large() {
+    let mut contract = Contract::new();
+    register_protocol_and_numeric_request(
+        &mut contract,
+        NumericAnswer {
+            max: I128(i128::MAX),
+            ..FEE_ANSWER
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "A numeric answer can have at most 24 decimals")]
+fn test_request_numeric_decision_when_decimals_are_too_many() {
+    let mut contract = Contract::new();
+    register_protocol_and_numeric_request(&mut contract, NumericAnswer { decimals: 25, ..FEE_ANSWER });
+}
+
+#[test]
+fn test_reveal_value_by_miner() {
+    let mut contract = Contract::new();
+    register_protocol_and_numeric_request(&mut contract, FEE_ANSWER);
+
+    let miner = get_default_miner_account();
+    register_and_commit_miners_value(&mut contract, std::slice::from_ref(&miner), 250);
+
+    Environment::with_account(miner).with_block_timestamp(REVEAL_MINER_TIME).create();
+
+    let result = contract.reveal_value_by_miner(DEFAULT_REQUEST_ID.to_string(), I128(250), DEFAULT_REVEAL_MESSAGE.to_string());
+
+    assert_eq!(result, RevealMinerResult::Success);
+    assert_logs(vec![Log::Event {
+        event_name: "reveal_miner_value".to_string(),
+        data: vec![
+            ("request_id", json![DEFAULT_REQUEST_ID]),
+            ("value", json!["250"]),
+            ("message", json![DEFAULT_REVEAL_MESSAGE]),
+        ],
+    }]);
+}
+
+#[test]
+fn test_reveal_value_by_miner_when_value_is_out_of_bounds() {
+    let mut contract = Contract::new();
+    register_protocol_and_numeric_request(&mut contract, FEE_ANSWER);
+
+    let miner = get_default_miner_account();
+    register_and_commit_miners_value(&mut contract, std::slice::from_ref(&miner), 10_001);
+
+    Environment::with_account(miner).with_block_timestamp(REVEAL_MINER_TIME).create();
+
+    let result = contract.reveal_value_by_miner(DEFAULT_REQUEST_ID.to_string(), I128(10_001), DEFAULT_REVEAL_MESSAGE.to_string());
+
+    assert_eq!(result, RevealMinerResult::Fail);
+    assert_logs(vec![Log::Message("Value out of bounds: 10001".to_string())]);
+}
+
+#[test]
+fn test_reveal_by_miner_when_request_is_numeric() {
+    let mut contract = Contract::new();
+    register_protocol_and_numeric_request(&mut contract, FEE_ANSWER);
+
+    let miner = get_default_miner_account();
+    register_and_commit_miners(&mut contract, std::slice::from_ref(&miner), 0);
+
+    Environment::with_account(miner).with_block_timestamp(REVEAL_MINER_TIME).create();
+
+    let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), 0, DEFAULT_REVEAL_MESSAGE.to_string());
+
+    assert_eq!(result, RevealMinerResult::Fail);
+    assert_logs(vec![Log::Message("Request expects a numeric answer".to_string())]);
+}
+
+#[test]
+fn test_reveal_value_by_miner_when_request_has_options() {
+    let mut contract = Contract::new();
+    register_protocol_and_request(&mut contract);
+
+    let miner = get_default_miner_account();
+    Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+    contract.register_miner();
+    contract.commit_by_miner(
+        DEFAULT_REQUEST_ID.to_string(),
+        hash_miner_value(DEFAULT_REQUEST_ID, &miner, 0, DEFAULT_REVEAL_MESSAGE),
+    );
+
+    Environment::with_account(miner).with_block_timestamp(REVEAL_MINER_TIME).create();
+
+    let result = contract.reveal_value_by_miner(DEFAULT_REQUEST_ID.to_string(), I128(0), DEFAULT_REVEAL_MESSAGE.to_string());
+
+    assert_eq!(result, RevealMinerResult::Fail);
+    assert_logs(vec![Log::Message("Request expects an option".to_string())]);
+}
+
+#[test]
+fn test_hash_miner_value() {
+    let contract = Contract::new();
+
+    let miner = get_default_miner_account();
+    Environment::with_account(miner.clone()).create();
+
+    let result = contract.hash_miner_value(DEFAULT_REQUEST_ID.to_string(), I128(-125), DEFAULT_REVEAL_MESSAGE.to_string());
+
+    assert_eq!(result, hash_miner_value(DEFAULT_REQUEST_ID, &miner, -125, DEFAULT_REVEAL_MESSAGE));
+}
+
+#[test]
+fn test_decision_is_the_median_of_the_values() {
+    let contract = setup_request(Aggregation::Median, &[100, 200, 300, 400, 500, 600, 700, 800, 900, 9_000]);
+
+    // @dev With an even count the two middle values are averaged
+    assert_eq!(contract.get_decision(DEFAULT_REQUEST_ID.to_string()), numeric_decision(Some(550)));
+}
+
+#[test]
+fn test_decision_is_the_trimmed_mean_of_the_values() {
+    let contract = setup_request(Aggregation::TrimmedMean, &[0, 1, 300, 300, 300, 301, 302, 303, 10_000, 10_000]);
+
+    // @dev The two lowest and the two highest values are left out
+    assert_eq!(contract.get_decision(DEFAULT_REQUEST_ID.to_string()), numeric_decision(Some(301)));
+}
+
+#[test]
+fn test_decision_uses_the_configured_trimmed_percentage() {
+    let config = Config {
+        trimmed_percentage: 10,
+        ..DEFAULT_CONFIG
+    };
+    let contract = setup_request_with_config(Aggregation::TrimmedMean, &[0, 1, 300, 300, 300, 301, 302, 303, 10_000, 10_000], config);
+
+    // @dev Only the lowest and the highest values are left out
+    assert_eq!(contract.get_decision(DEFAULT_REQUEST_ID.to_string()), numeric_decision(Some(1_475)));
+}
+
+#[test]
+fn test_reveal_value_by_miner_with_payload() {
+    let mut contract = Contract::new();
+    register_protocol_and_numeric_request(&mut contract, FEE_ANSWER);
+
+    let miner = get_default_miner_account();
+    let payload = AnswerPayload {
+        content_hash: "3910deb8f11de66388bddcc1eb1bf1e33319b71a18df2c1019e6d72c6d00f464".to_string(),
+        uri: "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_string(),
+    };
+    let message = format!("{}{}", payload.content_hash, payload.uri);
+
+    Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+    contract.register_miner();
+    contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), hash_miner_value(DEFAULT_REQUEST_ID, &miner, 250, &message));
+
+    Environment::with_account(miner).with_block_timestamp(REVEAL_MINER_TIME).create();
+
+    let result = contract.reveal_value_by_miner_with_payload(DEFAULT_REQUEST_ID.to_string(), I128(250), payload.clone());
+
+    assert_eq!(result, RevealMinerResult::Success);
+    assert_logs(vec![Log::Event {
+        event_name: "reveal_miner_value".to_string(),
+        data: vec![
+            ("request_id", json![DEFAULT_REQUEST_ID]),
+            ("value", json!["250"]),
+            ("payload", json!({ "content_hash": payload.content_hash, "uri": payload.uri })),
+        ],
+    }]);
+}
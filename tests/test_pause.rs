@@ -0,0 +1,164 @@
+use near_sdk::AccountId;
+use serde_json::json;
+
+use common::constants::{
+    COMMIT_VALIDATOR_TIME, DEFAULT_DEPOSIT_MINER, DEFAULT_MESSAGE_TO_REQUEST, DEFAULT_REQUEST_ID, DEFAULT_REVEAL_MESSAGE, OWNER, REVEAL_MINER_TIME,
+    REVEAL_TOPTEN_TIME,
+};
+use common::environment::Environment;
+use common::lifecycle::{generate_miners, register_and_commit_miners, register_protocol_and_request};
+use common::types::Log;
+use common::utils::{assert_logs, get_default_miner_account, get_default_protocol_account};
+
+use earthmind_rs::{Contract, PausableFeature, PauseState, RegisterMinerResult, Role};
+
+pub mod common;
+
+const PAUSER: &str = "pauser.near";
+
+fn account(account: &str) -> AccountId {
+    account.parse().unwrap()
+}
+
+fn setup_contract() -> Contract {
+    Environment::with_account(account(OWNER)).create();
+    let mut contract = Contract::new();
+    contract.grant_role(account(PAUSER), Role::Pauser);
+    contract
+}
+
+fn pause(contract: &mut Contract, feature: PausableFeature) {
+    Environment::with_account(account(PAUSER)).create();
+    contract.pause(feature);
+}
+
+#[test]
+fn test_pause() {
+    let mut contract = setup_contract();
+
+    pause(&mut contract, PausableFeature::Commit);
+
+    assert_eq!(
+        contract.get_pause_state(),
+        PauseState {
+            commit: true,
+            ..PauseState::default()
+        }
+    );
+    assert_logs(vec![Log::Event {
+        event_name: "paused".to_string(),
+        data: vec![("feature", json!["Commit"]), ("account", json![PAUSER])],
+    }]);
+}
+
+#[test]
+fn test_unpause() {
+    let mut contract = setup_contract();
+
+    pause(&mut contract, PausableFeature::Registration);
+
+    Environment::with_account(account(PAUSER)).create();
+    contract.unpause(PausableFeature::Registration);
+
+    assert_eq!(contract.get_pause_state(), PauseState::default());
+    assert_logs(vec![Log::Event {
+        event_name: "unpaused".to_string(),
+        data: vec![("feature", json!["Registration"]), ("account", json![PAUSER])],
+    }]);
+
+    Environment::with_account(get_default_miner_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_MINER)
+        .create();
+    assert_eq!(contract.register_miner(), RegisterMinerResult::Success);
+}
+
+#[test]
+#[should_panic(expected = "Only an account with one of the roles [Owner, Pauser] can call this method")]
+fn test_pause_when_caller_is_not_a_pauser() {
+    let mut contract = setup_contract();
+
+    Environment::with_account(get_default_miner_account()).create();
+    contract.pause(PausableFeature::Commit);
+}
+
+#[test]
+#[should_panic(expected = "Registration is paused")]
+fn test_register_miner_when_registration_is_paused() {
+    let mut contract = setup_contract();
+
+    pause(&mut contract, PausableFeature::Registration);
+
+    Environment::with_account(get_default_miner_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_MINER)
+        .create();
+    contract.register_miner();
+}
+
+#[test]
+#[should_panic(expected = "Request creation is paused")]
+fn test_request_governance_decision_when_requests_are_paused() {
+    let mut contract = setup_contract();
+
+    register_protocol_and_request(&mut contract);
+    pause(&mut contract, PausableFeature::Requests);
+
+    Environment::with_account(get_default_protocol_account()).create();
+    contract.request_governance_decision(format!("{} again", DEFAULT_MESSAGE_TO_REQUEST), None);
+}
+
+#[test]
+#[should_panic(expected = "Commits are paused")]
+fn test_commit_by_miner_when_commit_is_paused() {
+    let mut contract = setup_contract();
+
+    register_protocol_and_request(&mut contract);
+    pause(&mut contract, PausableFeature::Commit);
+
+    register_and_commit_miners(&mut contract, &generate_miners(1), 0);
+}
+
+#[test]
+#[should_panic(expected = "Reveals are paused")]
+fn test_reveal_by_miner_when_reveal_is_paused() {
+    let mut contract = setup_contract();
+
+    register_protocol_and_request(&mut contract);
+
+    let miners = generate_miners(1);
+    register_and_commit_miners(&mut contract, &miners, 0);
+    pause(&mut contract, PausableFeature::Reveal);
+
+    Environment::with_account(miners[0].clone()).with_block_timestamp(REVEAL_MINER_TIME).create();
+    contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), 0, DEFAULT_REVEAL_MESSAGE.to_string());
+}
+
+#[test]
+#[should_panic(expected = "Reveals are paused")]
+fn test_settle_miners_when_reveal_is_paused() {
+    let mut contract = setup_contract();
+
+    register_protocol_and_request(&mut contract);
+
+    // @dev The miner can't reveal while reveals are paused, so it can't be slashed for it either
+    register_and_commit_miners(&mut contract, &generate_miners(1), 0);
+    pause(&mut contract, PausableFeature::Reveal);
+
+    Environment::with_account(get_default_miner_account())
+        .with_block_timestamp(COMMIT_VALIDATOR_TIME)
+        .create();
+    contract.settle_miners(DEFAULT_REQUEST_ID.to_string());
+}
+
+#[test]
+#[should_panic(expected = "Reveals are paused")]
+fn test_settle_validators_when_reveal_is_paused() {
+    let mut contract = setup_contract();
+
+    register_protocol_and_request(&mut contract);
+    pause(&mut contract, PausableFeature::Reveal);
+
+    Environment::with_account(get_default_miner_account())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+    contract.settle_validators(DEFAULT_REQUEST_ID.to_string());
+}
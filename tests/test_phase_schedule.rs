@@ -0,0 +1,139 @@
+use near_sdk::NearToken;
+use serde_json::json;
+
+use common::constants::{
+    DEFAULT_CULTURE, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_MESSAGE_TO_REQUEST, DEFAULT_PROTOCOL_ACCOUNT_ID, DEFAULT_REQUEST_ID, DEFAULT_TIMESTAMP,
+};
+use common::environment::Environment;
+use common::types::Log;
+use common::utils::{assert_logs, get_default_protocol_account};
+
+use earthmind_rs::{Contract, Module, PhaseSchedule, RequestState, UpdateScheduleResult, DEFAULT_PHASE_SCHEDULE, MAX_PHASE_DURATION, MIN_PHASE_DURATION};
+
+pub mod common;
+
+const TEN_MINUTES: u64 = 10 * 60 * 1_000_000_000;
+
+const TEN_MINUTES_SCHEDULE: PhaseSchedule = PhaseSchedule {
+    commit_miners: TEN_MINUTES,
+    reveal_miners: TEN_MINUTES,
+    commit_validators: TEN_MINUTES,
+    reveal_validators: TEN_MINUTES,
+};
+
+fn register_protocol(contract: &mut Contract) {
+    Environment::with_account(get_default_protocol_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+
+    contract.register_protocol(DEFAULT_CULTURE.to_string(), vec![Module::TextPrompting]);
+}
+
+#[test]
+fn test_phase_schedule_defaults_when_protocol_is_registered() {
+    let mut contract = Contract::new();
+    register_protocol(&mut contract);
+
+    assert_eq!(contract.get_phase_schedule(get_default_protocol_account()), Some(DEFAULT_PHASE_SCHEDULE));
+}
+
+#[test]
+fn test_update_phase_schedule() {
+    let mut contract = Contract::new();
+    register_protocol(&mut contract);
+
+    Environment::with_account(get_default_protocol_account()).create();
+    let result = contract.update_phase_schedule(TEN_MINUTES_SCHEDULE);
+
+    assert_eq!(result, UpdateScheduleResult::Success);
+    assert_eq!(contract.get_phase_schedule(get_default_protocol_account()), Some(TEN_MINUTES_SCHEDULE));
+    assert_logs(vec![Log::Event {
+        event_name: "update_phase_schedule".to_string(),
+        data: vec![
+            ("account", json![DEFAULT_PROTOCOL_ACCOUNT_ID]),
+            (
+                "schedule",
+                json![{
+                    "commit_miners": TEN_MINUTES,
+                    "reveal_miners": TEN_MINUTES,
+                    "commit_validators": TEN_MINUTES,
+                    "reveal_validators": TEN_MINUTES,
+                }],
+            ),
+        ],
+    }]);
+}
+
+#[test]
+fn test_update_phase_schedule_when_protocol_is_not_registered() {
+    let mut contract = Contract::new();
+
+    Environment::with_account(get_default_protocol_account()).create();
+    let result = contract.update_phase_schedule(TEN_MINUTES_SCHEDULE);
+
+    assert_eq!(result, UpdateScheduleResult::NotRegisteredProtocol);
+    assert_eq!(contract.get_phase_schedule(get_default_protocol_account()), None);
+}
+
+#[test]
+fn test_update_phase_schedule_when_durations_are_out_of_bounds() {
+    let mut contract = Contract::new();
+    register_protocol(&mut contract);
+
+    Environment::with_account(get_default_protocol_account()).create();
+
+    let too_short = PhaseSchedule {
+        commit_miners: MIN_PHASE_DURATION - 1,
+        ..TEN_MINUTES_SCHEDULE
+    };
+    let too_long = PhaseSchedule {
+        reveal_validators: MAX_PHASE_DURATION + 1,
+        ..TEN_MINUTES_SCHEDULE
+    };
+
+    assert_eq!(contract.update_phase_schedule(too_short), UpdateScheduleResult::InvalidSchedule);
+    assert_eq!(contract.update_phase_schedule(too_long), UpdateScheduleResult::InvalidSchedule);
+    assert_eq!(contract.get_phase_schedule(get_default_protocol_account()), Some(DEFAULT_PHASE_SCHEDULE));
+}
+
+#[test]
+fn test_request_uses_the_schedule_of_its_protocol() {
+    let mut contract = Contract::new();
+    register_protocol(&mut contract);
+
+    Environment::with_account(get_default_protocol_account()).create();
+    contract.update_phase_schedule(TEN_MINUTES_SCHEDULE);
+
+    Environment::with_account(get_default_protocol_account())
+        .with_attached_deposit(NearToken::from_yoctonear(0))
+        .create();
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None);
+
+    // @dev With the default schedule the request would already be in the RevealValidators stage
+    Environment::with_account(get_default_protocol_account())
+        .with_block_timestamp(DEFAULT_TIMESTAMP + 7 * 60 * 1_000_000_000)
+        .create();
+    let request = contract.get_request(DEFAULT_REQUEST_ID.to_string()).unwrap();
+
+    assert_eq!(request.stage, RequestState::CommitMiners);
+    assert_eq!(request.commit_miners_deadline, DEFAULT_TIMESTAMP + TEN_MINUTES);
+    assert_eq!(request.reveal_validators_deadline, DEFAULT_TIMESTAMP + 4 * TEN_MINUTES);
+}
+
+#[test]
+fn test_request_keeps_its_schedule_when_protocol_schedule_changes() {
+    let mut contract = Contract::new();
+    register_protocol(&mut contract);
+
+    Environment::with_account(get_default_protocol_account()).create();
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None);
+    contract.update_phase_schedule(TEN_MINUTES_SCHEDULE);
+
+    Environment::with_account(get_default_protocol_account())
+        .with_block_timestamp(DEFAULT_TIMESTAMP + 3 * 60 * 1_000_000_000)
+        .create();
+    let request = contract.get_request(DEFAULT_REQUEST_ID.to_string()).unwrap();
+
+    assert_eq!(request.stage, RequestState::RevealMiners);
+    assert_eq!(request.commit_miners_deadline, DEFAULT_TIMESTAMP + DEFAULT_PHASE_SCHEDULE.commit_miners);
+}
@@ -4,7 +4,7 @@ use serde_json::json;
 use common::constants::{DEFAULT_DEPOSIT_MINER, MINER_1, MINER_2};
 use common::environment::Environment;
 use common::types::Log;
-use common::utils::{assert_logs, get_account_for_miner, get_default_miner_account};
+use common::utils::{assert_logs, assert_transfers, get_account_for_miner, get_default_miner_account};
 
 use earthmind_rs::{Contract, RegisterMinerResult};
 
@@ -65,7 +65,7 @@ fn test_register_multiple_miners() {
 fn test_register_miner_when_is_registered_returns_already_registered() {
     let miner_1 = get_default_miner_account();
 
-    Environment::with_account(miner_1).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+    Environment::with_account(miner_1.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
 
     let mut contract = Contract::new();
     contract.register_miner();
@@ -81,6 +81,9 @@ fn test_register_miner_when_is_registered_returns_already_registered() {
         },
         Log::Message("Attempted to register an already registered miner: miner1.near".to_string()),
     ]);
+
+    // @dev The deposit of the second registration is refunded
+    assert_transfers(vec![(miner_1, DEFAULT_DEPOSIT_MINER)]);
 }
 
 #[test]
@@ -4,7 +4,7 @@ use serde_json::json;
 use common::constants::{ACCOUNT_1, ACCOUNT_2, DEFAULT_CULTURE, DEFAULT_DEPOSIT_PROTOCOL};
 use common::environment::Environment;
 use common::types::Log;
-use common::utils::{assert_logs, get_account_for_protocol, get_default_protocol_account};
+use common::utils::{assert_logs, assert_transfers, get_account_for_protocol, get_default_protocol_account};
 
 use earthmind_rs::{Contract, Module, RegisterProtocolResult};
 
@@ -75,7 +75,9 @@ fn test_register_multiple_protocols() {
 fn test_register_protocol_when_is_registered_returns_already_registered() {
     let account_1 = get_default_protocol_account();
 
-    Environment::with_account(account_1).with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL).create();
+    Environment::with_account(account_1.clone())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
 
     let mut contract = Contract::new();
 
@@ -93,6 +95,9 @@ fn test_register_protocol_when_is_registered_returns_already_registered() {
         },
         Log::Message("Attempted to register an already registered account: account1.near".to_string()),
     ]);
+
+    // @dev The deposit of the second registration is refunded
+    assert_transfers(vec![(account_1, DEFAULT_DEPOSIT_PROTOCOL)]);
 }
 
 #[test]
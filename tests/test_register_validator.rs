@@ -4,7 +4,7 @@ use serde_json::json;
 use common::constants::{DEFAULT_DEPOSIT_VALIDATOR, VALIDATOR_1, VALIDATOR_2};
 use common::environment::Environment;
 use common::types::Log;
-use common::utils::{assert_logs, get_account_for_validator, get_default_validator_account};
+use common::utils::{assert_logs, assert_transfers, get_account_for_validator, get_default_validator_account};
 
 use earthmind_rs::{Contract, RegisterValidatorResult};
 
@@ -70,7 +70,9 @@ fn test_register_multiple_validators() {
 fn test_register_validator_when_is_registered_returns_already_registered() {
     let validator = get_default_validator_account();
 
-    Environment::with_account(validator).with_attached_deposit(DEFAULT_DEPOSIT_VALIDATOR).create();
+    Environment::with_account(validator.clone())
+        .with_attached_deposit(DEFAULT_DEPOSIT_VALIDATOR)
+        .create();
 
     let mut contract = Contract::new();
     contract.register_validator();
@@ -85,6 +87,9 @@ fn test_register_validator_when_is_registered_returns_already_registered() {
         },
         Log::Message("Attempted to register an already registered validator: validator1.near".to_string()),
     ]);
+
+    // @dev The deposit of the second registration is refunded
+    assert_transfers(vec![(validator, DEFAULT_DEPOSIT_VALIDATOR)]);
 }
 
 #[test]
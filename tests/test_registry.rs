@@ -0,0 +1,106 @@
+use near_sdk::NearToken;
+
+use common::constants::{DEFAULT_DEPOSIT_MINER, DEFAULT_DEPOSIT_VALIDATOR, DEFAULT_TIMESTAMP};
+use common::environment::Environment;
+use common::lifecycle::{generate_miners, generate_validators};
+
+use earthmind_rs::{Contract, ParticipantView};
+
+pub mod common;
+
+// @dev Every miner registers one second after the previous one
+fn setup_miners(count: usize) -> Contract {
+    let mut contract = Contract::new();
+
+    for (index, miner) in generate_miners(count).into_iter().enumerate() {
+        Environment::with_account(miner)
+            .with_attached_deposit(DEFAULT_DEPOSIT_MINER)
+            .with_block_timestamp(DEFAULT_TIMESTAMP + index as u64 * 1_000_000_000)
+            .create();
+        contract.register_miner();
+    }
+
+    contract
+}
+
+fn miner_view(index: usize) -> ParticipantView {
+    ParticipantView {
+        account: generate_miners(index + 1)[index].clone(),
+        stake: DEFAULT_DEPOSIT_MINER,
+        registered_at: DEFAULT_TIMESTAMP + index as u64 * 1_000_000_000,
+    }
+}
+
+#[test]
+fn test_get_miners() {
+    let contract = setup_miners(3);
+
+    assert_eq!(contract.get_miner_count(), 3);
+    assert_eq!(contract.get_miners(0, 10), vec![miner_view(0), miner_view(1), miner_view(2)]);
+}
+
+#[test]
+fn test_get_miners_with_pagination() {
+    let contract = setup_miners(5);
+
+    assert_eq!(contract.get_miners(0, 2), vec![miner_view(0), miner_view(1)]);
+    assert_eq!(contract.get_miners(2, 2), vec![miner_view(2), miner_view(3)]);
+    assert_eq!(contract.get_miners(4, 2), vec![miner_view(4)]);
+    assert_eq!(contract.get_miners(5, 2), vec![]);
+}
+
+#[test]
+fn test_get_miners_after_a_miner_unstakes_everything() {
+    let mut contract = setup_miners(3);
+
+    Environment::with_account(generate_miners(1)[0].clone()).create();
+    contract.unstake_miner(DEFAULT_DEPOSIT_MINER);
+
+    // @dev The last miner takes the slot of the removed one
+    assert_eq!(contract.get_miner_count(), 2);
+    assert_eq!(contract.get_miners(0, 10), vec![miner_view(2), miner_view(1)]);
+}
+
+#[test]
+fn test_get_validators() {
+    let mut contract = Contract::new();
+
+    let validators = generate_validators(2);
+
+    for validator in validators.iter() {
+        Environment::with_account(validator.clone())
+            .with_attached_deposit(DEFAULT_DEPOSIT_VALIDATOR)
+            .create();
+        contract.register_validator();
+    }
+
+    Environment::with_account(validators[1].clone())
+        .with_attached_deposit(NearToken::from_near(5))
+        .create();
+    contract.increase_validator_stake();
+
+    assert_eq!(contract.get_validator_count(), 2);
+    assert_eq!(
+        contract.get_validators(0, 10),
+        vec![
+            ParticipantView {
+                account: validators[0].clone(),
+                stake: DEFAULT_DEPOSIT_VALIDATOR,
+                registered_at: DEFAULT_TIMESTAMP,
+            },
+            ParticipantView {
+                account: validators[1].clone(),
+                stake: NearToken::from_near(15),
+                registered_at: DEFAULT_TIMESTAMP,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_get_validators_when_there_are_no_validators() {
+    let contract = Contract::new();
+
+    assert_eq!(contract.get_validator_count(), 0);
+    assert_eq!(contract.get_validators(0, 10), vec![]);
+}
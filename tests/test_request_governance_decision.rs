@@ -1,10 +1,11 @@
-use near_workspaces::AccountId;
+use near_sdk::{borsh, env, AccountId};
 use serde_json::json;
 
 use common::constants::{
     DEFAULT_CULTURE, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_MESSAGE_TO_REQUEST, DEFAULT_MINER_ANSWER, DEFAULT_REQUEST_ID, DEFAULT_VALIDATOR_ANSWER,
 };
 use common::environment::Environment;
+use common::lifecycle::register_protocol_and_request_with_options;
 use common::types::Log;
 use common::utils::{assert_logs, generate_validator_answer, get_default_miner_account, get_default_protocol_account, get_default_validator_account};
 
@@ -13,7 +14,7 @@ use earthmind_rs::{Contract, Module, RegisterRequestResult};
 pub mod common;
 
 #[test]
-fn test_request_governance_decision_when_is_registered_returns_already_registered() {
+fn test_request_governance_decision_when_message_is_repeated() {
     let mut contract = Contract::new();
 
     let protocol = get_default_protocol_account();
@@ -24,11 +25,17 @@ fn test_request_governance_decision_when_is_registered_returns_already_registere
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
     contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
 
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None);
 
-    let result = contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    let result = contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None);
 
-    assert_eq!(result, RegisterRequestResult::AlreadyRegistered);
+    let second_request_id = contract.get_request_id(protocol.clone(), DEFAULT_MESSAGE_TO_REQUEST.to_string(), 1);
+
+    assert_eq!(result, RegisterRequestResult::Success(second_request_id.clone()));
+
+    assert_ne!(second_request_id, DEFAULT_REQUEST_ID);
+    assert!(contract.get_request_by_id(second_request_id.clone()));
+    assert_eq!(contract.get_request_nonce(protocol.clone()), 2);
 
     assert_logs(vec![
         Log::Event {
@@ -39,10 +46,109 @@ fn test_request_governance_decision_when_is_registered_returns_already_registere
             event_name: "register_request".to_string(),
             data: vec![("request_id", json![DEFAULT_REQUEST_ID])],
         },
-        Log::Message("Attempted to register an already registered request: 73ead60176d724e462dbfa8d49506177bb13bec748cf5af5019b6d1da63e204b".to_string()),
+        Log::Event {
+            event_name: "register_request".to_string(),
+            data: vec![("request_id", json![second_request_id])],
+        },
     ]);
 }
 
+#[test]
+fn test_get_request_id() {
+    let contract = Contract::new();
+
+    let protocol = get_default_protocol_account();
+    let message = DEFAULT_MESSAGE_TO_REQUEST.to_string();
+
+    for nonce in [0u64, 12] {
+        let encoded = borsh::to_vec(&(protocol.clone(), message.clone(), nonce)).unwrap();
+        assert_eq!(
+            contract.get_request_id(protocol.clone(), message.clone(), nonce),
+            hex::encode(env::keccak256(&encoded))
+        );
+    }
+
+    assert_eq!(contract.get_request_id(protocol.clone(), message.clone(), 0), DEFAULT_REQUEST_ID);
+    assert_eq!(
+        contract.get_legacy_request_id(protocol.clone(), message.clone()),
+        hex::encode(env::keccak256(format!("{}{}", protocol, message).as_bytes()))
+    );
+}
+
+#[test]
+fn test_request_governance_decision_when_sender_and_message_concatenate_to_another_request() {
+    let mut contract = Contract::new();
+
+    let protocol = get_default_protocol_account();
+    let other_protocol: AccountId = format!("{}should", protocol).parse().unwrap();
+    let message = "should we repeat this question?".to_string();
+    let other_message = " we repeat this question?".to_string();
+
+    // @dev The concatenations of both senders and messages are the same, but their encodings are not
+    Environment::with_account(other_protocol.clone())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+    contract.register_protocol(DEFAULT_CULTURE.to_string(), vec![Module::TextPrompting]);
+    let other_result = contract.request_governance_decision(other_message.clone(), None);
+
+    Environment::with_account(protocol.clone())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+    contract.register_protocol(DEFAULT_CULTURE.to_string(), vec![Module::TextPrompting]);
+    let result = contract.request_governance_decision(message.clone(), None);
+
+    let request_id = contract.get_request_id(protocol.clone(), message.clone(), 0);
+    let other_request_id = contract.get_request_id(other_protocol.clone(), other_message.clone(), 0);
+
+    assert_ne!(request_id, other_request_id);
+    assert_eq!(
+        contract.get_legacy_request_id(protocol.clone(), message),
+        contract.get_legacy_request_id(other_protocol.clone(), other_message)
+    );
+    assert_eq!(result, RegisterRequestResult::Success(request_id.clone()));
+    assert_eq!(other_result, RegisterRequestResult::Success(other_request_id.clone()));
+    assert_eq!(contract.get_request(request_id).unwrap().sender, protocol);
+    assert_eq!(contract.get_request(other_request_id).unwrap().sender, other_protocol);
+}
+
+#[test]
+fn test_request_governance_decision_with_options() {
+    let mut contract = Contract::new();
+    register_protocol_and_request_with_options(&mut contract, &["Approve", "Reject", "Abstain"]);
+
+    let request = contract.get_request(DEFAULT_REQUEST_ID.to_string()).unwrap();
+
+    assert_eq!(request.options, vec!["Approve".to_string(), "Reject".to_string(), "Abstain".to_string()]);
+}
+
+#[test]
+#[should_panic(expected = "A request needs between 2 and 10 options")]
+fn test_request_governance_decision_when_options_are_not_enough() {
+    let mut contract = Contract::new();
+    register_protocol_and_request_with_options(&mut contract, &["Yes"]);
+}
+
+#[test]
+#[should_panic(expected = "A request needs between 2 and 10 options")]
+fn test_request_governance_decision_when_options_are_too_many() {
+    let mut contract = Contract::new();
+    register_protocol_and_request_with_options(&mut contract, &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11"]);
+}
+
+#[test]
+#[should_panic(expected = "Options must have between 1 and 100 bytes")]
+fn test_request_governance_decision_when_option_is_empty() {
+    let mut contract = Contract::new();
+    register_protocol_and_request_with_options(&mut contract, &["Yes", ""]);
+}
+
+#[test]
+#[should_panic(expected = "Options must be unique")]
+fn test_request_governance_decision_when_options_are_repeated() {
+    let mut contract = Contract::new();
+    register_protocol_and_request_with_options(&mut contract, &["Yes", "Yes"]);
+}
+
 // Hash miner answer
 
 #[test]
@@ -53,7 +159,7 @@ fn test_hash_miner_answer() {
     Environment::with_account(miner).create();
 
     let request_id = DEFAULT_REQUEST_ID.to_string();
-    let answer = true;
+    let answer = 0;
     let message = "It's a cool NFT".to_string();
 
     let result = contract.hash_miner_answer(request_id, answer, message);
@@ -61,6 +167,16 @@ fn test_hash_miner_answer() {
     assert_eq!(result, DEFAULT_MINER_ANSWER);
 }
 
+#[test]
+#[should_panic(expected = "Message is longer than 1024 bytes")]
+fn test_hash_miner_answer_when_message_is_too_long() {
+    let contract = Contract::new();
+
+    Environment::with_account(get_default_miner_account()).create();
+
+    contract.hash_miner_answer(DEFAULT_REQUEST_ID.to_string(), 0, "a".repeat(1025));
+}
+
 // Hash validator answer
 #[test]
 fn test_hash_validator_answer() {
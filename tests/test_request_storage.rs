@@ -0,0 +1,135 @@
+use serde_json::json;
+
+use common::constants::{
+    COMMIT_VALIDATOR_TIME, DEFAULT_CULTURE, DEFAULT_DEPOSIT_MINER, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_DEPOSIT_VALIDATOR, DEFAULT_MESSAGE_TO_REQUEST,
+    DEFAULT_REVEAL_MESSAGE, REVEAL_MINER_TIME, REVEAL_VALIDATOR_TIME,
+};
+use common::environment::Environment;
+use common::lifecycle::{generate_miners, generate_validators, hash_miner_answer, hash_validator_answer};
+use common::types::Log;
+use common::utils::{assert_logs, get_default_protocol_account};
+
+use earthmind_rs::{CommitMinerResult, CommitValidatorResult, Contract, Module, RevealMinerResult, RevealValidatorResult};
+
+pub mod common;
+
+const OTHER_MESSAGE: &str = "Should we increase the rewards of our stakers?";
+
+fn request_id(contract: &Contract, message: &str, nonce: u64) -> String {
+    contract.get_request_id(get_default_protocol_account(), message.to_string(), nonce)
+}
+
+// @dev The protocol opens two requests at the same time
+fn setup_concurrent_requests() -> (Contract, String, String) {
+    let mut contract = Contract::new();
+
+    Environment::with_account(get_default_protocol_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+
+    contract.register_protocol(DEFAULT_CULTURE.to_string(), vec![Module::TextPrompting]);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None);
+    contract.request_governance_decision(OTHER_MESSAGE.to_string(), None);
+
+    let first_request = request_id(&contract, DEFAULT_MESSAGE_TO_REQUEST, 0);
+    let second_request = request_id(&contract, OTHER_MESSAGE, 1);
+
+    (contract, first_request, second_request)
+}
+
+#[test]
+fn test_miner_proposals_are_isolated_between_requests() {
+    let (mut contract, first_request, second_request) = setup_concurrent_requests();
+
+    let miner = generate_miners(1)[0].clone();
+    Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+    contract.register_miner();
+
+    let first_hash = hash_miner_answer(&first_request, &miner, 0, DEFAULT_REVEAL_MESSAGE);
+    let second_hash = hash_miner_answer(&second_request, &miner, 1, DEFAULT_REVEAL_MESSAGE);
+
+    assert_eq!(contract.commit_by_miner(first_request.clone(), first_hash), CommitMinerResult::Success);
+    assert_eq!(contract.commit_by_miner(second_request.clone(), second_hash), CommitMinerResult::Success);
+
+    Environment::with_account(miner).with_block_timestamp(REVEAL_MINER_TIME).create();
+
+    let first_reveal = contract.reveal_by_miner(first_request, 0, DEFAULT_REVEAL_MESSAGE.to_string());
+    let second_reveal = contract.reveal_by_miner(second_request, 1, DEFAULT_REVEAL_MESSAGE.to_string());
+
+    assert_eq!(first_reveal, RevealMinerResult::Success);
+    assert_eq!(second_reveal, RevealMinerResult::Success);
+}
+
+#[test]
+fn test_votes_are_isolated_between_requests() {
+    let (mut contract, first_request, second_request) = setup_concurrent_requests();
+
+    let miners = generate_miners(10);
+
+    for miner in miners.iter() {
+        Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+        contract.register_miner();
+
+        for request_id in [&first_request, &second_request] {
+            let hash = hash_miner_answer(request_id, miner, 0, DEFAULT_REVEAL_MESSAGE);
+            contract.commit_by_miner(request_id.clone(), hash);
+        }
+
+        Environment::with_account(miner.clone()).with_block_timestamp(REVEAL_MINER_TIME).create();
+
+        for request_id in [&first_request, &second_request] {
+            contract.reveal_by_miner(request_id.clone(), 0, DEFAULT_REVEAL_MESSAGE.to_string());
+        }
+    }
+
+    let validator = generate_validators(1)[0].clone();
+    Environment::with_account(validator.clone())
+        .with_attached_deposit(DEFAULT_DEPOSIT_VALIDATOR)
+        .create();
+    contract.register_validator();
+
+    Environment::with_account(validator.clone())
+        .with_block_timestamp(COMMIT_VALIDATOR_TIME)
+        .create();
+
+    let hash = hash_validator_answer(&first_request, &validator, &miners, DEFAULT_REVEAL_MESSAGE);
+    assert_eq!(contract.commit_by_validator(first_request.clone(), hash), CommitValidatorResult::Success);
+
+    Environment::with_account(validator).with_block_timestamp(REVEAL_VALIDATOR_TIME).create();
+
+    let result = contract.reveal_by_validator(first_request.clone(), miners.clone(), DEFAULT_REVEAL_MESSAGE.to_string());
+    assert_eq!(result, RevealValidatorResult::Success);
+
+    Environment::with_account(miners[0].clone()).create();
+
+    contract.votes_for_miner(first_request.clone(), miners[0].clone());
+    contract.votes_for_miner(second_request.clone(), miners[0].clone());
+
+    assert_logs(vec![
+        Log::Message("miner1.near have 10 votes".to_string()),
+        Log::Message("miner don't have votes".to_string()),
+    ]);
+
+    assert!(contract.get_score_breakdown(first_request, miners[0].clone()).is_some());
+    assert!(contract.get_score_breakdown(second_request, miners[0].clone()).is_none());
+}
+
+#[test]
+fn test_request_events_are_logged_for_each_request() {
+    let (_, first_request, second_request) = setup_concurrent_requests();
+
+    assert_logs(vec![
+        Log::Event {
+            event_name: "register_protocol".to_string(),
+            data: vec![("account", json![get_default_protocol_account()])],
+        },
+        Log::Event {
+            event_name: "register_request".to_string(),
+            data: vec![("request_id", json![first_request])],
+        },
+        Log::Event {
+            event_name: "register_request".to_string(),
+            data: vec![("request_id", json![second_request])],
+        },
+    ]);
+}
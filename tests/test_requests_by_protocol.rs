@@ -0,0 +1,99 @@
+use near_sdk::NearToken;
+
+use common::constants::{DEFAULT_CULTURE, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_TIMESTAMP};
+use common::environment::Environment;
+use common::utils::{get_account_for_protocol, get_default_protocol_account};
+
+use earthmind_rs::{Contract, Module, RequestState, StageFilter, TWO_MINUTES};
+
+pub mod common;
+
+const MESSAGES: [&str; 3] = [
+    "Should we add this new NFT to our protocol?",
+    "Should we increase the rewards of our stakers?",
+    "Should we list a new token?",
+];
+
+// @dev The nonce of every request is its position in `MESSAGES`
+fn request_id(contract: &Contract, index: usize) -> String {
+    contract.get_request_id(get_default_protocol_account(), MESSAGES[index].to_string(), index as u64)
+}
+
+// @dev The requests start at 0, 3 and 4.5 stages, so at 4.5 stages they are ended, in progress and open
+fn setup_requests() -> Contract {
+    let mut contract = Contract::new();
+
+    Environment::with_account(get_default_protocol_account())
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .create();
+    contract.register_protocol(DEFAULT_CULTURE.to_string(), vec![Module::TextPrompting]);
+
+    let start_times = [DEFAULT_TIMESTAMP, DEFAULT_TIMESTAMP + 3 * TWO_MINUTES, DEFAULT_TIMESTAMP + 9 * TWO_MINUTES / 2];
+
+    for (message, start_time) in MESSAGES.iter().zip(start_times) {
+        Environment::with_account(get_default_protocol_account())
+            .with_attached_deposit(NearToken::from_yoctonear(0))
+            .with_block_timestamp(start_time)
+            .create();
+        contract.request_governance_decision(message.to_string(), None);
+    }
+
+    contract
+}
+
+fn request_ids(contract: &Contract, from_index: u32, limit: u32, stage_filter: Option<StageFilter>) -> Vec<String> {
+    contract
+        .get_requests_by_protocol(get_default_protocol_account(), from_index, limit, stage_filter)
+        .into_iter()
+        .map(|request| request.request_id)
+        .collect()
+}
+
+#[test]
+fn test_get_requests_by_protocol() {
+    let contract = setup_requests();
+
+    let requests = contract.get_requests_by_protocol(get_default_protocol_account(), 0, 10, None);
+
+    let stages: Vec<RequestState> = requests.into_iter().map(|request| request.stage).collect();
+    assert_eq!(stages, vec![RequestState::Ended, RequestState::RevealMiners, RequestState::CommitMiners]);
+    let expected: Vec<String> = (0..MESSAGES.len()).map(|index| request_id(&contract, index)).collect();
+    assert_eq!(request_ids(&contract, 0, 10, None), expected);
+}
+
+#[test]
+fn test_get_requests_by_protocol_with_pagination() {
+    let contract = setup_requests();
+
+    assert_eq!(request_ids(&contract, 0, 2, None), vec![request_id(&contract, 0), request_id(&contract, 1)]);
+    assert_eq!(request_ids(&contract, 2, 2, None), vec![request_id(&contract, 2)]);
+    assert_eq!(request_ids(&contract, 3, 2, None), Vec::<String>::new());
+}
+
+#[test]
+fn test_get_requests_by_protocol_with_stage_filter() {
+    let contract = setup_requests();
+
+    assert_eq!(request_ids(&contract, 0, 10, Some(StageFilter::Open)), vec![request_id(&contract, 2)]);
+    assert_eq!(request_ids(&contract, 0, 10, Some(StageFilter::InProgress)), vec![request_id(&contract, 1)]);
+    assert_eq!(request_ids(&contract, 0, 10, Some(StageFilter::Ended)), vec![request_id(&contract, 0)]);
+}
+
+#[test]
+fn test_get_requests_by_protocol_when_protocol_has_no_requests() {
+    let contract = setup_requests();
+
+    let requests = contract.get_requests_by_protocol(get_account_for_protocol("account2.near"), 0, 10, None);
+
+    assert!(requests.is_empty());
+}
+
+#[test]
+fn test_get_requests_by_protocol_with_stage_filter_and_pagination() {
+    let contract = setup_requests();
+
+    // @dev The filter applies to the page, so the open request is only in the second page
+    assert_eq!(request_ids(&contract, 0, 2, Some(StageFilter::Open)), Vec::<String>::new());
+    assert_eq!(request_ids(&contract, 2, 2, Some(StageFilter::Open)), vec![request_id(&contract, 2)]);
+    assert_eq!(request_ids(&contract, 0, 2, Some(StageFilter::Ended)), vec![request_id(&contract, 0)]);
+}
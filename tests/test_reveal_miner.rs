@@ -3,10 +3,11 @@ use common::constants::{
     REVEAL_MINER_TIME,
 };
 use common::environment::Environment;
+use common::lifecycle::{hash_miner_answer, register_protocol_and_request};
 use common::types::Log;
 use common::utils::{assert_logs, get_account_for_miner, get_default_miner_account, get_default_protocol_account};
 
-use earthmind_rs::{Contract, Module, RevealMinerResult};
+use earthmind_rs::{AnswerPayload, Contract, Module, RevealMinerResult};
 
 use serde_json::json;
 
@@ -24,7 +25,7 @@ fn test_reveal_by_miner() {
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
     contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -55,7 +56,7 @@ fn test_reveal_by_miner() {
 
     Environment::with_account(miner).with_block_timestamp(REVEAL_MINER_TIME).create();
 
-    let answer = true;
+    let answer = 0;
     let message = "It's a cool NFT";
 
     let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, message.to_string());
@@ -84,7 +85,7 @@ fn test_reveal_by_miner_when_miner_is_not_registered() {
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
     contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -117,7 +118,7 @@ fn test_reveal_by_miner_when_miner_is_not_registered() {
     let miner_2 = get_account_for_miner(MINER_2);
     Environment::with_account(miner_2).with_block_timestamp(REVEAL_MINER_TIME).create();
 
-    let answer = true;
+    let answer = 0;
     let message = "It's a cool NFT";
 
     let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, message.to_string());
@@ -139,7 +140,7 @@ fn test_reveal_by_miner_when_request_is_not_registered() {
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
     contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None);
 
     assert_logs(vec![
         Log::Event {
@@ -161,7 +162,7 @@ fn test_reveal_by_miner_when_request_is_not_registered() {
     contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), DEFAULT_MINER_ANSWER.to_string());
 
     let fail_request_id = "0504fbdd23f833749a13dcde971238ba62bdde0ed02ea5424f5a522f50fae725".to_string();
-    let answer = true;
+    let answer = 0;
     let message = "It's a cool NFT".to_string();
 
     let result = contract.reveal_by_miner(fail_request_id, answer, message);
@@ -193,7 +194,7 @@ fn test_reveal_by_miner_when_proposal_is_already_reveal() {
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
     contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None);
 
     assert_logs(vec![
         Log::Event {
@@ -227,7 +228,7 @@ fn test_reveal_by_miner_when_proposal_is_already_reveal() {
 
     Environment::with_account(miner_1).with_block_timestamp(REVEAL_MINER_TIME).create();
 
-    let answer = true;
+    let answer = 0;
     let message = "It's a cool NFT".to_string();
 
     contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, message.clone());
@@ -261,7 +262,7 @@ fn test_reveal_by_miner_when_answer_not_equal() {
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
     contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None);
 
     assert_logs(vec![
         Log::Event {
@@ -295,10 +296,137 @@ fn test_reveal_by_miner_when_answer_not_equal() {
 
     Environment::with_account(miner_1).with_block_timestamp(REVEAL_MINER_TIME).create();
 
-    let answer = false;
+    let answer = 1;
     let message = "It's a cool NFT";
     let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, message.to_string());
 
     assert_eq!(result, RevealMinerResult::Fail);
     assert_logs(vec![Log::Message("Answer don't match".to_string())]);
 }
+
+#[test]
+fn test_reveal_by_miner_when_option_is_not_valid() {
+    let mut contract = Contract::new();
+    register_protocol_and_request(&mut contract);
+
+    let miner = get_default_miner_account();
+    let answer = 2;
+    let message = "It's a cool NFT";
+
+    Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+    contract.register_miner();
+    let hash = hash_miner_answer(DEFAULT_REQUEST_ID, &miner, answer, message);
+    contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), hash);
+
+    Environment::with_account(miner).with_block_timestamp(REVEAL_MINER_TIME).create();
+
+    let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, message.to_string());
+
+    assert_eq!(result, RevealMinerResult::Fail);
+    assert_logs(vec![Log::Message("Invalid option: 2".to_string())]);
+}
+
+fn default_payload() -> AnswerPayload {
+    AnswerPayload {
+        content_hash: "3910deb8f11de66388bddcc1eb1bf1e33319b71a18df2c1019e6d72c6d00f464".to_string(),
+        uri: "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_string(),
+    }
+}
+
+// @dev The commit binds the payload as the message made of its content hash followed by its uri
+fn commit_payload(contract: &mut Contract, payload: &AnswerPayload) {
+    register_protocol_and_request(contract);
+
+    let miner = get_default_miner_account();
+    let message = format!("{}{}", payload.content_hash, payload.uri);
+
+    Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+    contract.register_miner();
+    contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), hash_miner_answer(DEFAULT_REQUEST_ID, &miner, 0, &message));
+
+    Environment::with_account(miner).with_block_timestamp(REVEAL_MINER_TIME).create();
+}
+
+#[test]
+fn test_reveal_by_miner_with_payload() {
+    let mut contract = Contract::new();
+    let payload = default_payload();
+    commit_payload(&mut contract, &payload);
+
+    let result = contract.reveal_by_miner_with_payload(DEFAULT_REQUEST_ID.to_string(), 0, payload.clone());
+
+    assert_eq!(result, RevealMinerResult::Success);
+    assert_logs(vec![Log::Event {
+        event_name: "reveal_miner".to_string(),
+        data: vec![
+            ("request_id", json![DEFAULT_REQUEST_ID]),
+            ("answer", json![0]),
+            ("payload", json!({ "content_hash": payload.content_hash, "uri": payload.uri })),
+        ],
+    }]);
+}
+
+#[test]
+fn test_reveal_by_miner_with_payload_when_uri_does_not_match() {
+    let mut contract = Contract::new();
+    commit_payload(&mut contract, &default_payload());
+
+    let payload = AnswerPayload {
+        uri: "https://example.com/answer.json".to_string(),
+        ..default_payload()
+    };
+    let result = contract.reveal_by_miner_with_payload(DEFAULT_REQUEST_ID.to_string(), 0, payload);
+
+    assert_eq!(result, RevealMinerResult::Fail);
+    assert_logs(vec![Log::Message("Answer don't match".to_string())]);
+}
+
+#[test]
+fn test_reveal_by_miner_with_payload_when_content_hash_is_not_valid() {
+    let mut contract = Contract::new();
+    let payload = AnswerPayload {
+        content_hash: "not a hash".to_string(),
+        ..default_payload()
+    };
+    commit_payload(&mut contract, &payload);
+
+    let result = contract.reveal_by_miner_with_payload(DEFAULT_REQUEST_ID.to_string(), 0, payload);
+
+    assert_eq!(result, RevealMinerResult::Fail);
+    assert_logs(vec![Log::Message("Invalid content hash: not a hash".to_string())]);
+}
+
+#[test]
+fn test_reveal_by_miner_with_payload_when_uri_is_too_long() {
+    let mut contract = Contract::new();
+    let payload = AnswerPayload {
+        uri: "a".repeat(513),
+        ..default_payload()
+    };
+    commit_payload(&mut contract, &payload);
+
+    let result = contract.reveal_by_miner_with_payload(DEFAULT_REQUEST_ID.to_string(), 0, payload);
+
+    assert_eq!(result, RevealMinerResult::Fail);
+    assert_logs(vec![Log::Message("URI must have between 1 and 512 bytes".to_string())]);
+}
+
+#[test]
+fn test_reveal_by_miner_when_message_is_too_long() {
+    let mut contract = Contract::new();
+    register_protocol_and_request(&mut contract);
+
+    let miner = get_default_miner_account();
+    let message = "a".repeat(1025);
+
+    Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+    contract.register_miner();
+    contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), hash_miner_answer(DEFAULT_REQUEST_ID, &miner, 0, &message));
+
+    Environment::with_account(miner).with_block_timestamp(REVEAL_MINER_TIME).create();
+
+    let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), 0, message);
+
+    assert_eq!(result, RevealMinerResult::Fail);
+    assert_logs(vec![Log::Message("Message is longer than 1024 bytes".to_string())]);
+}
@@ -28,7 +28,7 @@ fn test_reveal_by_validator() {
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
     contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -77,7 +77,7 @@ fn test_reveal_by_validator() {
 
     for miners in registered_miners {
         Environment::with_account(miners.clone()).with_block_timestamp(REVEAL_MINER_TIME).create();
-        let answer = true;
+        let answer = 0;
         let message = "It's a cool NFT";
         let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, message.to_string());
         assert_eq!(result, RevealMinerResult::Success);
@@ -131,7 +131,7 @@ fn test_reveal_by_validator_when_miner_dont_have_a_commit_answer() {
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
     contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -200,7 +200,7 @@ fn test_reveal_by_validator_when_miner_have_a_commit_answer_but_not_revealed() {
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
     contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -253,7 +253,7 @@ fn test_reveal_by_validator_when_miner_have_a_commit_answer_but_not_revealed() {
             break;
         }
         Environment::with_account(miners.clone()).with_block_timestamp(REVEAL_MINER_TIME).create();
-        let answer = true;
+        let answer = 0;
         let message = "It's a cool NFT";
         let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, message.to_string());
         assert_eq!(result, RevealMinerResult::Success);
@@ -300,7 +300,7 @@ fn test_reveal_by_validator_when_validator_is_not_registered() {
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
     contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -359,7 +359,7 @@ fn test_reveal_by_validator_when_request_is_not_registered() {
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
     contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -432,7 +432,7 @@ fn test_reveal_by_validator_when_proposal_is_already_reveal() {
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
     contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -482,7 +482,7 @@ fn test_reveal_by_validator_when_proposal_is_already_reveal() {
 
     for miners in registered_miners {
         Environment::with_account(miners.clone()).with_block_timestamp(REVEAL_MINER_TIME).create();
-        let answer = true;
+        let answer = 0;
         let message = "It's a cool NFT";
         let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, message.to_string());
         assert_eq!(result, RevealMinerResult::Success);
@@ -542,7 +542,7 @@ fn test_reveal_by_validator_when_answer_not_equal() {
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
     contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -592,7 +592,7 @@ fn test_reveal_by_validator_when_answer_not_equal() {
 
     for miners in registered_miners {
         Environment::with_account(miners.clone()).with_block_timestamp(REVEAL_MINER_TIME).create();
-        let answer = true;
+        let answer = 0;
         let message = "It's a cool NFT";
         let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, message.to_string());
         assert_eq!(result, RevealMinerResult::Success);
@@ -607,7 +607,7 @@ fn test_reveal_by_validator_when_answer_not_equal() {
     }
 
     let extra_miner: AccountId = "miner11.near".parse().unwrap();
-    let extra_miner_answer = "b574e5145b78602616f316e59a3556819d249c9297dfaab7938875bbb77c18d9".to_string();
+    let extra_miner_answer = "ee9149ea6eead471e9d154ba5139f25010de2faf0b1fc2d36893fbd19811d71f".to_string();
     Environment::with_account(extra_miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT).create();
     contract.register_miner();
 
@@ -625,7 +625,7 @@ fn test_reveal_by_validator_when_answer_not_equal() {
     ]);
     Environment::with_account(extra_miner).with_block_timestamp(REVEAL_MINER_TIME).create();
 
-    let answer = true;
+    let answer = 0;
     let message = "It's a cool NFT";
     contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, message.to_string());
 
@@ -673,7 +673,7 @@ fn test_reveal_by_validator_when_vote_for_miner_not_registered() {
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
     contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -745,7 +745,7 @@ fn test_reveal_by_validator_when_miner_is_duplicated() {
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
     contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -816,7 +816,7 @@ fn test_reveal_by_validator_when_dont_have_a_commit_answer() {
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
     contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -865,7 +865,7 @@ fn test_reveal_by_validator_when_dont_have_a_commit_answer() {
 
     for miners in registered_miners {
         Environment::with_account(miners.clone()).with_block_timestamp(REVEAL_MINER_TIME).create();
-        let answer = true;
+        let answer = 0;
         let message = "It's a cool NFT";
         let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, message.to_string());
         assert_eq!(result, RevealMinerResult::Success);
@@ -0,0 +1,96 @@
+use near_sdk::NearToken;
+use serde_json::json;
+
+use common::constants::{DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_REQUEST_ID, REVEAL_TOPTEN_TIME, VALIDATOR_1};
+use common::environment::Environment;
+use common::lifecycle::{
+    generate_miners, generate_validators, register_and_commit_miners, register_and_commit_validator, register_protocol_and_request, reveal_miners,
+    reveal_validator,
+};
+use common::types::Log;
+use common::utils::{assert_logs, assert_transfers, get_default_validator_account};
+
+use earthmind_rs::{ClaimRewardsResult, Contract, REWARD_PER_REQUEST};
+
+pub mod common;
+
+// @dev Ten miners reveal, the first validator reveals its ranking and the second one doesn't
+fn setup_finalized_request() -> Contract {
+    let mut contract = Contract::new();
+    register_protocol_and_request(&mut contract);
+
+    let miners = generate_miners(10);
+    register_and_commit_miners(&mut contract, &miners, 0);
+    reveal_miners(&mut contract, &miners, 0);
+
+    let validators = generate_validators(2);
+    register_and_commit_validator(&mut contract, &validators[0], &miners);
+    register_and_commit_validator(&mut contract, &validators[1], &miners);
+    reveal_validator(&mut contract, &validators[0], &miners);
+
+    Environment::with_account(validators[0].clone())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+    contract.finalize_request(DEFAULT_REQUEST_ID.to_string());
+
+    contract
+}
+
+#[test]
+fn test_rewards_are_distributed_on_finalization() {
+    let contract = setup_finalized_request();
+
+    let miners = generate_miners(10);
+    let validators = generate_validators(2);
+
+    for miner in miners {
+        assert_eq!(contract.get_pending_rewards(miner), NearToken::from_millinear(70));
+    }
+
+    assert_eq!(contract.get_pending_rewards(validators[0].clone()), NearToken::from_millinear(300));
+    assert_eq!(contract.get_pending_rewards(validators[1].clone()), NearToken::from_yoctonear(0));
+    assert_eq!(contract.get_treasury_balance(), DEFAULT_DEPOSIT_PROTOCOL.saturating_sub(REWARD_PER_REQUEST));
+}
+
+#[test]
+fn test_rewards_are_distributed_only_once() {
+    let mut contract = setup_finalized_request();
+
+    contract.finalize_request(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(contract.get_pending_rewards(get_default_validator_account()), NearToken::from_millinear(300));
+    assert_eq!(contract.get_treasury_balance(), DEFAULT_DEPOSIT_PROTOCOL.saturating_sub(REWARD_PER_REQUEST));
+}
+
+#[test]
+fn test_claim_rewards() {
+    let mut contract = setup_finalized_request();
+
+    let validator = get_default_validator_account();
+    Environment::with_account(validator.clone()).create();
+
+    let result = contract.claim_rewards();
+
+    assert_eq!(result, ClaimRewardsResult::Success);
+    assert_eq!(contract.get_pending_rewards(validator.clone()), NearToken::from_yoctonear(0));
+
+    assert_logs(vec![Log::Event {
+        event_name: "claim_rewards".to_string(),
+        data: vec![("account", json![VALIDATOR_1]), ("amount", json![NearToken::from_millinear(300)])],
+    }]);
+    assert_transfers(vec![(validator, NearToken::from_millinear(300))]);
+}
+
+#[test]
+fn test_claim_rewards_when_there_is_nothing_to_claim() {
+    let mut contract = setup_finalized_request();
+
+    let validator = generate_validators(2)[1].clone();
+    Environment::with_account(validator).create();
+
+    let result = contract.claim_rewards();
+
+    assert_eq!(result, ClaimRewardsResult::NothingToClaim);
+    assert_logs(vec![Log::Message("No rewards to claim for: validator2.near".to_string())]);
+    assert_transfers(vec![]);
+}
@@ -0,0 +1,191 @@
+use near_sdk::{AccountId, NearToken};
+use serde_json::json;
+
+use common::constants::{DEFAULT_DEPOSIT_PROTOCOL, OWNER};
+use common::environment::Environment;
+use common::lifecycle::register_protocol_and_request;
+use common::types::Log;
+use common::utils::{assert_logs, assert_transfers};
+
+use earthmind_rs::{Contract, Role, UpdateConfigResult, UpdateRoleResult, WithdrawTreasuryResult, DEFAULT_CONFIG};
+
+pub mod common;
+
+const ADMIN: &str = "admin.near";
+const TREASURER: &str = "treasurer.near";
+const NEW_OWNER: &str = "new_owner.near";
+
+fn account(account: &str) -> AccountId {
+    account.parse().unwrap()
+}
+
+fn setup_contract() -> Contract {
+    Environment::with_account(account(OWNER)).create();
+    let mut contract = Contract::new();
+    contract.grant_role(account(ADMIN), Role::Admin);
+    contract
+}
+
+#[test]
+fn test_grant_role() {
+    let mut contract = setup_contract();
+
+    Environment::with_account(account(ADMIN)).create();
+    let result = contract.grant_role(account(TREASURER), Role::Treasurer);
+
+    assert_eq!(result, UpdateRoleResult::Success);
+    assert!(contract.has_role(account(TREASURER), Role::Treasurer));
+    assert_eq!(contract.get_roles(account(TREASURER)), vec![Role::Treasurer]);
+    assert_logs(vec![Log::Event {
+        event_name: "role_granted".to_string(),
+        data: vec![("account", json![TREASURER]), ("role", json!["Treasurer"]), ("granted_by", json![ADMIN])],
+    }]);
+}
+
+#[test]
+fn test_grant_role_when_already_granted() {
+    let mut contract = setup_contract();
+
+    Environment::with_account(account(OWNER)).create();
+    let result = contract.grant_role(account(ADMIN), Role::Admin);
+
+    assert_eq!(result, UpdateRoleResult::AlreadyGranted);
+    assert_eq!(contract.get_roles(account(ADMIN)), vec![Role::Admin]);
+}
+
+#[test]
+#[should_panic(expected = "Only an account with one of the roles [Owner] can call this method")]
+fn test_grant_admin_role_when_caller_is_an_admin() {
+    let mut contract = setup_contract();
+
+    Environment::with_account(account(ADMIN)).create();
+    contract.grant_role(account(TREASURER), Role::Admin);
+}
+
+#[test]
+#[should_panic(expected = "The owner is changed with transfer_ownership")]
+fn test_grant_owner_role() {
+    let mut contract = setup_contract();
+
+    Environment::with_account(account(OWNER)).create();
+    contract.grant_role(account(ADMIN), Role::Owner);
+}
+
+#[test]
+fn test_revoke_role() {
+    let mut contract = setup_contract();
+
+    Environment::with_account(account(OWNER)).create();
+    let result = contract.revoke_role(account(ADMIN), Role::Admin);
+
+    assert_eq!(result, UpdateRoleResult::Success);
+    assert!(!contract.has_role(account(ADMIN), Role::Admin));
+    assert_logs(vec![Log::Event {
+        event_name: "role_revoked".to_string(),
+        data: vec![("account", json![ADMIN]), ("role", json!["Admin"]), ("revoked_by", json![OWNER])],
+    }]);
+}
+
+#[test]
+fn test_revoke_role_when_not_granted() {
+    let mut contract = setup_contract();
+
+    Environment::with_account(account(OWNER)).create();
+    let result = contract.revoke_role(account(TREASURER), Role::Treasurer);
+
+    assert_eq!(result, UpdateRoleResult::NotGranted);
+}
+
+#[test]
+fn test_admin_can_update_config() {
+    let mut contract = setup_contract();
+
+    Environment::with_account(account(ADMIN)).create();
+
+    assert_eq!(contract.update_config(DEFAULT_CONFIG), UpdateConfigResult::Success);
+}
+
+#[test]
+fn test_transfer_ownership() {
+    let mut contract = setup_contract();
+
+    Environment::with_account(account(OWNER)).create();
+    contract.transfer_ownership(account(NEW_OWNER));
+
+    assert_eq!(contract.get_owner(), account(OWNER));
+    assert_eq!(contract.get_pending_owner(), Some(account(NEW_OWNER)));
+    assert_logs(vec![Log::Event {
+        event_name: "ownership_transfer_started".to_string(),
+        data: vec![("owner", json![OWNER]), ("pending_owner", json![NEW_OWNER])],
+    }]);
+
+    Environment::with_account(account(NEW_OWNER)).create();
+    contract.accept_ownership();
+
+    assert_eq!(contract.get_owner(), account(NEW_OWNER));
+    assert_eq!(contract.get_pending_owner(), None);
+    assert_eq!(contract.get_roles(account(NEW_OWNER)), vec![Role::Owner]);
+    assert!(!contract.has_role(account(OWNER), Role::Owner));
+    assert_logs(vec![Log::Event {
+        event_name: "ownership_transferred".to_string(),
+        data: vec![("previous_owner", json![OWNER]), ("owner", json![NEW_OWNER])],
+    }]);
+}
+
+#[test]
+#[should_panic(expected = "Only the pending owner can accept the ownership")]
+fn test_accept_ownership_when_caller_is_not_the_pending_owner() {
+    let mut contract = setup_contract();
+
+    Environment::with_account(account(OWNER)).create();
+    contract.transfer_ownership(account(NEW_OWNER));
+
+    Environment::with_account(account(ADMIN)).create();
+    contract.accept_ownership();
+}
+
+#[test]
+fn test_withdraw_treasury() {
+    let mut contract = setup_contract();
+
+    Environment::with_account(account(OWNER)).create();
+    contract.grant_role(account(TREASURER), Role::Treasurer);
+
+    register_protocol_and_request(&mut contract);
+
+    Environment::with_account(account(TREASURER)).create();
+    let result = contract.withdraw_treasury(NearToken::from_near(2));
+
+    assert_eq!(result, WithdrawTreasuryResult::Success);
+    assert_eq!(
+        contract.get_treasury_balance(),
+        DEFAULT_DEPOSIT_PROTOCOL.saturating_sub(NearToken::from_near(2))
+    );
+    assert_transfers(vec![(account(TREASURER), NearToken::from_near(2))]);
+    assert_logs(vec![Log::Event {
+        event_name: "withdraw_treasury".to_string(),
+        data: vec![("account", json![TREASURER]), ("amount", json![NearToken::from_near(2)])],
+    }]);
+}
+
+#[test]
+fn test_withdraw_treasury_when_amount_exceeds_balance() {
+    let mut contract = setup_contract();
+
+    Environment::with_account(account(OWNER)).create();
+    contract.grant_role(account(TREASURER), Role::Treasurer);
+
+    Environment::with_account(account(TREASURER)).create();
+    let result = contract.withdraw_treasury(NearToken::from_near(1));
+
+    assert_eq!(result, WithdrawTreasuryResult::InvalidAmount);
+}
+
+#[test]
+#[should_panic(expected = "Only an account with one of the roles [Treasurer] can call this method")]
+fn test_withdraw_treasury_when_caller_is_not_a_treasurer() {
+    let mut contract = setup_contract();
+
+    Environment::with_account(account(OWNER)).create();
+    contract.withdraw_treasury(NearToken::from_near(1));
+}
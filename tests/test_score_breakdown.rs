@@ -0,0 +1,99 @@
+use near_sdk::AccountId;
+
+use common::constants::{DEFAULT_REQUEST_ID, REVEAL_TOPTEN_TIME};
+use common::environment::Environment;
+use common::lifecycle::{
+    generate_miners, generate_validators, register_and_commit_miners, register_and_commit_validator, register_protocol_and_request, reveal_miners,
+    reveal_validator,
+};
+
+use earthmind_rs::{Contract, VoteScore, POSITION_POINTS};
+
+pub mod common;
+
+// @dev The first validator ranks the miners in order and the second one in reverse order
+fn setup_ranked_request() -> (Contract, Vec<AccountId>, Vec<AccountId>) {
+    let mut contract = Contract::new();
+    register_protocol_and_request(&mut contract);
+
+    let miners = generate_miners(10);
+    register_and_commit_miners(&mut contract, &miners, 0);
+    reveal_miners(&mut contract, &miners, 0);
+
+    let reversed: Vec<AccountId> = miners.iter().rev().cloned().collect();
+
+    let validators = generate_validators(2);
+    register_and_commit_validator(&mut contract, &validators[0], &miners);
+    register_and_commit_validator(&mut contract, &validators[1], &reversed);
+    reveal_validator(&mut contract, &validators[0], &miners);
+    reveal_validator(&mut contract, &validators[1], &reversed);
+
+    (contract, miners, validators)
+}
+
+#[test]
+fn test_score_breakdown_records_every_ranking() {
+    let (contract, miners, validators) = setup_ranked_request();
+
+    let breakdown = contract.get_score_breakdown(DEFAULT_REQUEST_ID.to_string(), miners[2].clone());
+
+    assert_eq!(
+        breakdown,
+        Some(vec![
+            VoteScore {
+                validator: validators[0].clone(),
+                position: 2,
+                points: POSITION_POINTS[2],
+                weight: 1,
+                score: 8,
+            },
+            VoteScore {
+                validator: validators[1].clone(),
+                position: 7,
+                points: POSITION_POINTS[7],
+                weight: 1,
+                score: 3,
+            },
+        ])
+    );
+}
+
+#[test]
+fn test_score_breakdown_when_miner_was_not_ranked() {
+    let (contract, _, _) = setup_ranked_request();
+
+    let breakdown = contract.get_score_breakdown(DEFAULT_REQUEST_ID.to_string(), "miner11.near".parse().unwrap());
+
+    assert!(breakdown.is_none());
+}
+
+#[test]
+fn test_get_top_10_voters_uses_position_points() {
+    let mut contract = Contract::new();
+    register_protocol_and_request(&mut contract);
+
+    let miners = generate_miners(11);
+    register_and_commit_miners(&mut contract, &miners, 0);
+    reveal_miners(&mut contract, &miners, 0);
+
+    // @dev Both validators rank the first miner last, so it scores less than the eleventh miner ranked first once
+    let first_ranking: Vec<AccountId> = miners[1..10].iter().chain(&miners[..1]).cloned().collect();
+    let second_ranking: Vec<AccountId> = miners[10..].iter().chain(&miners[2..10]).chain(&miners[..1]).cloned().collect();
+
+    let validators = generate_validators(2);
+    register_and_commit_validator(&mut contract, &validators[0], &first_ranking);
+    register_and_commit_validator(&mut contract, &validators[1], &second_ranking);
+    reveal_validator(&mut contract, &validators[0], &first_ranking);
+    reveal_validator(&mut contract, &validators[1], &second_ranking);
+
+    Environment::with_account(validators[0].clone())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+
+    contract.finalize_request(DEFAULT_REQUEST_ID.to_string());
+
+    let top_ten = contract.get_top_10_voters(DEFAULT_REQUEST_ID.to_string());
+
+    assert!(top_ten.contains(&(miners[10].clone(), 10)));
+    assert!(!top_ten.iter().any(|(miner, _)| *miner == miners[0]));
+}
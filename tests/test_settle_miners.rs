@@ -0,0 +1,141 @@
+use near_sdk::NearToken;
+use serde_json::json;
+
+use common::constants::{
+    COMMIT_VALIDATOR_TIME, DEFAULT_CULTURE, DEFAULT_DEPOSIT_MINER, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_MESSAGE_TO_REQUEST, DEFAULT_REQUEST_ID, MINER_2,
+    REVEAL_MINER_TIME,
+};
+use common::environment::Environment;
+use common::types::Log;
+use common::utils::{assert_logs, default_miners_commit_answer, get_account_for_miner, get_default_miner_account, get_default_protocol_account};
+
+use earthmind_rs::{Contract, Module, RevealMinerResult, SettleResult};
+
+pub mod common;
+
+fn setup_request_with_one_unrevealed_miner() -> Contract {
+    let mut contract = Contract::new();
+
+    let protocol = get_default_protocol_account();
+    Environment::with_account(protocol).with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL).create();
+
+    let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
+    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None);
+
+    let default_answer_miners = default_miners_commit_answer();
+    let miners = [get_default_miner_account(), get_account_for_miner(MINER_2)];
+
+    for (index, miner) in miners.iter().enumerate() {
+        Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+        contract.register_miner();
+        contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), default_answer_miners[index].clone());
+    }
+
+    // @dev Only the first miner reveals its answer
+    Environment::with_account(miners[0].clone()).with_block_timestamp(REVEAL_MINER_TIME).create();
+    let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), 0, "It's a cool NFT".to_string());
+    assert_eq!(result, RevealMinerResult::Success);
+
+    contract
+}
+
+#[test]
+fn test_settle_miners_slashes_unrevealed_commits() {
+    let mut contract = setup_request_with_one_unrevealed_miner();
+
+    let miner_1 = get_default_miner_account();
+    let miner_2 = get_account_for_miner(MINER_2);
+
+    Environment::with_account(miner_1.clone()).with_block_timestamp(COMMIT_VALIDATOR_TIME).create();
+
+    let result = contract.settle_miners(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(result, SettleResult::Success);
+    assert_eq!(contract.get_miner_stake(miner_1), Some(DEFAULT_DEPOSIT_MINER));
+    assert_eq!(contract.get_miner_stake(miner_2), Some(NearToken::from_millinear(900)));
+    assert_eq!(
+        contract.get_treasury_balance(),
+        DEFAULT_DEPOSIT_PROTOCOL.saturating_add(NearToken::from_millinear(100))
+    );
+
+    assert_logs(vec![Log::Event {
+        event_name: "slash_miner".to_string(),
+        data: vec![
+            ("request_id", json![DEFAULT_REQUEST_ID]),
+            ("miner", json![MINER_2]),
+            ("amount", json![NearToken::from_millinear(100)]),
+        ],
+    }]);
+}
+
+#[test]
+fn test_settle_miners_slashes_the_unbonding_stake() {
+    let mut contract = setup_request_with_one_unrevealed_miner();
+
+    let miner_2 = get_account_for_miner(MINER_2);
+
+    // @dev The miner unstakes everything but the minimum stake before the settlement
+    Environment::with_account(miner_2.clone())
+        .with_attached_deposit(NearToken::from_near(9))
+        .create();
+    contract.increase_miner_stake();
+    contract.unstake_miner(NearToken::from_near(9));
+
+    Environment::with_account(miner_2.clone()).with_block_timestamp(COMMIT_VALIDATOR_TIME).create();
+
+    let result = contract.settle_miners(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(result, SettleResult::Success);
+    assert_eq!(contract.get_miner_stake(miner_2.clone()), Some(DEFAULT_DEPOSIT_MINER));
+    assert_eq!(
+        contract.get_miner_unbonding_stake(miner_2).map(|unbonding| unbonding.amount),
+        Some(NearToken::from_near(8))
+    );
+    assert_eq!(
+        contract.get_treasury_balance(),
+        DEFAULT_DEPOSIT_PROTOCOL.saturating_add(NearToken::from_near(1))
+    );
+}
+
+#[test]
+fn test_settle_miners_when_already_settled() {
+    let mut contract = setup_request_with_one_unrevealed_miner();
+
+    Environment::with_account(get_default_miner_account())
+        .with_block_timestamp(COMMIT_VALIDATOR_TIME)
+        .create();
+
+    contract.settle_miners(DEFAULT_REQUEST_ID.to_string());
+    let result = contract.settle_miners(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(result, SettleResult::AlreadySettled);
+    assert_eq!(
+        contract.get_treasury_balance(),
+        DEFAULT_DEPOSIT_PROTOCOL.saturating_add(NearToken::from_millinear(100))
+    );
+}
+
+#[test]
+fn test_settle_miners_when_request_is_not_registered() {
+    let mut contract = Contract::new();
+
+    Environment::with_account(get_default_miner_account()).create();
+
+    let result = contract.settle_miners(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(result, SettleResult::Fail);
+    assert_logs(vec![Log::Message(format!("Request is not registered: {}", DEFAULT_REQUEST_ID))]);
+}
+
+#[test]
+#[should_panic(expected = "RevealMiners stage has not ended")]
+fn test_settle_miners_during_reveal_stage() {
+    let mut contract = setup_request_with_one_unrevealed_miner();
+
+    Environment::with_account(get_default_miner_account())
+        .with_block_timestamp(REVEAL_MINER_TIME)
+        .create();
+
+    contract.settle_miners(DEFAULT_REQUEST_ID.to_string());
+}
@@ -0,0 +1,189 @@
+use near_sdk::test_utils::get_logs;
+use near_sdk::NearToken;
+use serde_json::{json, Value};
+
+use common::constants::{DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_DEPOSIT_VALIDATOR, DEFAULT_REQUEST_ID, REVEAL_TOPTEN_TIME, REVEAL_VALIDATOR_TIME};
+use common::environment::Environment;
+use common::lifecycle::{
+    generate_miners, generate_validators, register_and_commit_miners, register_and_commit_validator, register_protocol_and_request, reveal_miners,
+    reveal_validator,
+};
+use common::utils::get_default_validator_account;
+
+use earthmind_rs::{Config, Contract, SettleResult, UpdateConfigResult, DEFAULT_CONFIG};
+
+pub mod common;
+
+// @dev Validators 1 and 2 agree on the consensus, validator 3 ranks six miners outside of it
+// and validator 4 never reveals its commit.
+fn setup_request_with_outlier_validators() -> Contract {
+    let mut contract = Contract::new();
+    register_protocol_and_request(&mut contract);
+
+    let miners = generate_miners(16);
+    register_and_commit_miners(&mut contract, &miners, 0);
+    reveal_miners(&mut contract, &miners, 0);
+
+    let validators = generate_validators(4);
+    let consensus_ranking = &miners[0..10];
+    let outlier_ranking = &miners[6..16];
+
+    register_and_commit_validator(&mut contract, &validators[0], consensus_ranking);
+    register_and_commit_validator(&mut contract, &validators[1], consensus_ranking);
+    register_and_commit_validator(&mut contract, &validators[2], outlier_ranking);
+    register_and_commit_validator(&mut contract, &validators[3], consensus_ranking);
+
+    reveal_validator(&mut contract, &validators[0], consensus_ranking);
+    reveal_validator(&mut contract, &validators[1], consensus_ranking);
+    reveal_validator(&mut contract, &validators[2], outlier_ranking);
+
+    contract
+}
+
+#[test]
+fn test_settle_validators_slashes_unrevealed_and_outlier_validators() {
+    let mut contract = setup_request_with_outlier_validators();
+
+    let validators = generate_validators(4);
+    Environment::with_account(validators[0].clone())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+
+    let result = contract.settle_validators(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(result, SettleResult::Success);
+    assert_eq!(contract.get_validator_stake(validators[0].clone()), Some(DEFAULT_DEPOSIT_VALIDATOR));
+    assert_eq!(contract.get_validator_stake(validators[1].clone()), Some(DEFAULT_DEPOSIT_VALIDATOR));
+    assert_eq!(contract.get_validator_stake(validators[2].clone()), Some(NearToken::from_near(9)));
+    assert_eq!(contract.get_validator_stake(validators[3].clone()), Some(NearToken::from_near(9)));
+    assert_eq!(
+        contract.get_treasury_balance(),
+        DEFAULT_DEPOSIT_PROTOCOL.saturating_add(NearToken::from_near(2))
+    );
+
+    let logs = get_logs();
+    assert_eq!(logs.len(), 1);
+
+    let log_event: Value = serde_json::from_str(logs[0].trim_start_matches("EVENT_JSON:")).unwrap();
+    let expected_event = json!({
+        "standard": "emip001",
+        "version": "1.0.0",
+        "event": "slash_validator",
+        "data": [
+            {
+                "request_id": DEFAULT_REQUEST_ID,
+                "validator": validators[2],
+                "amount": NearToken::from_near(1),
+                "reason": "OutlierRanking",
+            },
+            {
+                "request_id": DEFAULT_REQUEST_ID,
+                "validator": validators[3],
+                "amount": NearToken::from_near(1),
+                "reason": "NotRevealed",
+            },
+        ]
+    });
+
+    assert_eq!(log_event, expected_event);
+}
+
+#[test]
+fn test_settle_validators_slashes_the_unbonding_stake() {
+    let mut contract = setup_request_with_outlier_validators();
+
+    let validator = generate_validators(4)[3].clone();
+
+    // @dev The validator that never reveals unstakes everything but the minimum stake before the settlement
+    Environment::with_account(validator.clone())
+        .with_attached_deposit(NearToken::from_near(90))
+        .create();
+    contract.increase_validator_stake();
+    contract.unstake_validator(NearToken::from_near(90));
+
+    Environment::with_account(validator.clone()).with_block_timestamp(REVEAL_TOPTEN_TIME).create();
+
+    let result = contract.settle_validators(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(result, SettleResult::Success);
+    assert_eq!(contract.get_validator_stake(validator.clone()), Some(DEFAULT_DEPOSIT_VALIDATOR));
+    assert_eq!(
+        contract.get_validator_unbonding_stake(validator).map(|unbonding| unbonding.amount),
+        Some(NearToken::from_near(80))
+    );
+    assert_eq!(
+        contract.get_treasury_balance(),
+        DEFAULT_DEPOSIT_PROTOCOL.saturating_add(NearToken::from_near(11))
+    );
+}
+
+#[test]
+fn test_settle_validators_uses_the_configured_deviation_threshold() {
+    let mut contract = setup_request_with_outlier_validators();
+
+    // @dev The outlier ranking has six miners outside of the top ten
+    Environment::with_account(contract.get_owner()).create();
+    let config = Config {
+        validator_deviation_threshold: 6,
+        ..DEFAULT_CONFIG
+    };
+    assert_eq!(contract.update_config(config), UpdateConfigResult::Success);
+
+    let validators = generate_validators(4);
+    Environment::with_account(validators[0].clone())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+
+    let result = contract.settle_validators(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(result, SettleResult::Success);
+    assert_eq!(contract.get_validator_stake(validators[2].clone()), Some(DEFAULT_DEPOSIT_VALIDATOR));
+    assert_eq!(contract.get_validator_stake(validators[3].clone()), Some(NearToken::from_near(9)));
+}
+
+#[test]
+fn test_outlier_validators_get_no_rewards() {
+    let mut contract = setup_request_with_outlier_validators();
+
+    let validators = generate_validators(4);
+    Environment::with_account(validators[0].clone())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+
+    contract.finalize_request(DEFAULT_REQUEST_ID.to_string());
+
+    // @dev The validator share of 0.3 near is split between the two validators that agree on the consensus
+    assert_eq!(contract.get_pending_rewards(validators[0].clone()), NearToken::from_millinear(150));
+    assert_eq!(contract.get_pending_rewards(validators[1].clone()), NearToken::from_millinear(150));
+    assert_eq!(contract.get_pending_rewards(validators[2].clone()), NearToken::from_yoctonear(0));
+}
+
+#[test]
+fn test_settle_validators_when_already_settled() {
+    let mut contract = setup_request_with_outlier_validators();
+
+    Environment::with_account(get_default_validator_account())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+
+    contract.settle_validators(DEFAULT_REQUEST_ID.to_string());
+    let result = contract.settle_validators(DEFAULT_REQUEST_ID.to_string());
+
+    assert_eq!(result, SettleResult::AlreadySettled);
+    assert_eq!(
+        contract.get_treasury_balance(),
+        DEFAULT_DEPOSIT_PROTOCOL.saturating_add(NearToken::from_near(2))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Not stage ended")]
+fn test_settle_validators_before_request_ended() {
+    let mut contract = setup_request_with_outlier_validators();
+
+    Environment::with_account(get_default_validator_account())
+        .with_block_timestamp(REVEAL_VALIDATOR_TIME)
+        .create();
+
+    contract.settle_validators(DEFAULT_REQUEST_ID.to_string());
+}
@@ -0,0 +1,188 @@
+use near_sdk::NearToken;
+use serde_json::json;
+
+use common::constants::{
+    DEFAULT_CULTURE, DEFAULT_DEPOSIT_MINER, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_MESSAGE_TO_REQUEST, DEFAULT_MINER_ANSWER, DEFAULT_REQUEST_ID, DEFAULT_TIMESTAMP,
+    MINER_1, VALIDATOR_1,
+};
+use common::environment::Environment;
+use common::types::Log;
+use common::utils::{assert_logs, assert_transfers, get_default_miner_account, get_default_protocol_account, get_default_validator_account};
+
+use earthmind_rs::{CommitMinerResult, Contract, Module, UnbondingStake, UnstakeResult, WithdrawResult, UNBONDING_PERIOD};
+
+pub mod common;
+
+#[test]
+fn test_unstake_miner() {
+    let mut contract = Contract::new();
+
+    let miner = get_default_miner_account();
+    Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+    contract.register_miner();
+
+    let result = contract.unstake_miner(DEFAULT_DEPOSIT_MINER);
+
+    assert_eq!(result, UnstakeResult::Success);
+    assert!(!contract.is_miner_registered(miner.clone()));
+    assert_eq!(
+        contract.get_miner_unbonding_stake(miner),
+        Some(UnbondingStake {
+            amount: DEFAULT_DEPOSIT_MINER,
+            unlock_time: DEFAULT_TIMESTAMP + UNBONDING_PERIOD,
+        })
+    );
+
+    assert_logs(vec![
+        Log::Event {
+            event_name: "register_miner".to_string(),
+            data: vec![("miner", json![MINER_1])],
+        },
+        Log::Event {
+            event_name: "unstake_miner".to_string(),
+            data: vec![
+                ("miner", json![MINER_1]),
+                ("amount", json![DEFAULT_DEPOSIT_MINER]),
+                ("unlock_time", json![DEFAULT_TIMESTAMP + UNBONDING_PERIOD]),
+            ],
+        },
+    ]);
+}
+
+#[test]
+fn test_unstake_miner_when_miner_is_not_registered() {
+    let mut contract = Contract::new();
+
+    let miner = get_default_miner_account();
+    Environment::with_account(miner).create();
+
+    let result = contract.unstake_miner(DEFAULT_DEPOSIT_MINER);
+
+    assert_eq!(result, UnstakeResult::NotRegistered);
+    assert_logs(vec![Log::Message("Miner not registered: miner1.near".to_string())]);
+}
+
+#[test]
+fn test_unstake_validator_when_remaining_stake_is_less_than_min_stake() {
+    let mut contract = Contract::new();
+
+    let validator = get_default_validator_account();
+    Environment::with_account(validator.clone())
+        .with_attached_deposit(NearToken::from_near(15))
+        .create();
+    contract.register_validator();
+
+    let result = contract.unstake_validator(NearToken::from_near(10));
+
+    assert_eq!(result, UnstakeResult::InvalidAmount);
+    assert!(contract.is_validator_registered(validator.clone()));
+    assert!(contract.get_validator_unbonding_stake(validator).is_none());
+
+    assert_logs(vec![
+        Log::Event {
+            event_name: "register_validator".to_string(),
+            data: vec![("validator", json![VALIDATOR_1])],
+        },
+        Log::Message("Remaining stake is less than the minimum stake".to_string()),
+    ]);
+}
+
+#[test]
+fn test_withdraw_miner_when_stake_is_still_unbonding() {
+    let mut contract = Contract::new();
+
+    let miner = get_default_miner_account();
+    Environment::with_account(miner.clone()).with_attached_deposit(DEFAULT_DEPOSIT_MINER).create();
+    contract.register_miner();
+    contract.unstake_miner(DEFAULT_DEPOSIT_MINER);
+
+    Environment::with_account(miner)
+        .with_block_timestamp(DEFAULT_TIMESTAMP + UNBONDING_PERIOD - 1)
+        .create();
+
+    let result = contract.withdraw_miner();
+
+    assert_eq!(result, WithdrawResult::StillUnbonding);
+    assert_transfers(vec![]);
+}
+
+#[test]
+fn test_withdraw_validator_after_unbonding_period() {
+    let mut contract = Contract::new();
+
+    let validator = get_default_validator_account();
+    Environment::with_account(validator.clone())
+        .with_attached_deposit(NearToken::from_near(15))
+        .create();
+    contract.register_validator();
+    contract.unstake_validator(NearToken::from_near(5));
+
+    Environment::with_account(validator.clone())
+        .with_block_timestamp(DEFAULT_TIMESTAMP + UNBONDING_PERIOD)
+        .create();
+
+    let result = contract.withdraw_validator();
+
+    assert_eq!(result, WithdrawResult::Success);
+    assert!(contract.is_validator_registered(validator.clone()));
+    assert!(contract.get_validator_unbonding_stake(validator.clone()).is_none());
+
+    assert_transfers(vec![(validator, NearToken::from_near(5))]);
+
+    assert_logs(vec![Log::Event {
+        event_name: "withdraw_validator".to_string(),
+        data: vec![("validator", json![VALIDATOR_1]), ("amount", json![NearToken::from_near(5)])],
+    }]);
+
+    let result = contract.withdraw_validator();
+
+    assert_eq!(result, WithdrawResult::NothingToWithdraw);
+}
+
+#[test]
+fn test_withdraw_miner_when_miner_has_unrevealed_commit() {
+    let mut contract = Contract::new();
+
+    let miner = get_default_miner_account();
+    Environment::with_account(miner.clone()).with_attached_deposit(NearToken::from_near(2)).create();
+    contract.register_miner();
+    contract.unstake_miner(NearToken::from_near(1));
+
+    // @dev A new request starts once the unbonding period is over
+    let request_time = DEFAULT_TIMESTAMP + UNBONDING_PERIOD;
+    let protocol = get_default_protocol_account();
+    Environment::with_account(protocol)
+        .with_attached_deposit(DEFAULT_DEPOSIT_PROTOCOL)
+        .with_block_timestamp(request_time)
+        .create();
+
+    let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
+    contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None);
+
+    Environment::with_account(miner.clone()).with_block_timestamp(request_time).create();
+    let result = contract.commit_by_miner(DEFAULT_REQUEST_ID.to_string(), DEFAULT_MINER_ANSWER.to_string());
+    assert_eq!(result, CommitMinerResult::Success);
+
+    let result = contract.withdraw_miner();
+
+    assert_eq!(result, WithdrawResult::PendingCommits);
+    assert_logs(vec![
+        Log::Event {
+            event_name: "commit_miner".to_string(),
+            data: vec![("request_id", json![DEFAULT_REQUEST_ID]), ("answer", json![DEFAULT_MINER_ANSWER])],
+        },
+        Log::Message("Account has unrevealed commits in open requests: miner1.near".to_string()),
+    ]);
+
+    // @dev The unrevealed commit keeps blocking the withdrawal until the miners are settled
+    Environment::with_account(miner).with_block_timestamp(request_time + UNBONDING_PERIOD).create();
+
+    let result = contract.withdraw_miner();
+    assert_eq!(result, WithdrawResult::PendingCommits);
+
+    contract.settle_miners(DEFAULT_REQUEST_ID.to_string());
+
+    let result = contract.withdraw_miner();
+    assert_eq!(result, WithdrawResult::Success);
+}
@@ -0,0 +1,308 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::store::LookupMap;
+use near_sdk::{env, AccountId, NearToken};
+use serde_json::json;
+
+use common::constants::{
+    CONTRACT_ACCOUNT_ID, DEFAULT_CULTURE, DEFAULT_DEPOSIT_MINER, DEFAULT_DEPOSIT_PROTOCOL, DEFAULT_DEPOSIT_VALIDATOR, DEFAULT_MINER_ANSWER, DEFAULT_REQUEST_ID,
+    OWNER, REVEAL_TOPTEN_TIME,
+};
+use common::environment::Environment;
+use common::lifecycle::{generate_miners, register_and_commit_miners, register_protocol_and_request};
+use common::types::Log;
+use common::utils::{assert_function_calls, assert_logs, get_default_protocol_account, get_default_validator_account};
+
+use earthmind_rs::{
+    CommitMinerResult, Contract, FinalizeResult, Module, ParticipantView, RegisterRequestResult, RequestState, SettleResult, StateVersion, ValidatorProposal,
+    DEFAULT_PHASE_SCHEDULE,
+};
+
+pub mod common;
+
+const STATE_KEY: &[u8] = b"STATE";
+const BASELINE_MESSAGE: &str = "Should we add this new NFT to our protocol?";
+// @dev The baseline contract derived the id of a request from the protocol account and the message only
+const BASELINE_REQUEST_ID: &str = "73ead60176d724e462dbfa8d49506177bb13bec748cf5af5019b6d1da63e204b";
+
+// @dev Layouts of the baseline contract, which stored its state without versions
+#[derive(BorshDeserialize, BorshSerialize)]
+struct BaselineContract {
+    protocols: LookupMap<AccountId, BaselineProtocol>,
+    requests: LookupMap<String, BaselineRequest>,
+    miners: LookupMap<AccountId, NearToken>,
+    validators: LookupMap<AccountId, NearToken>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+struct BaselineProtocol {
+    account: AccountId,
+    culture: String,
+    modules: Vec<Module>,
+    registration_fee: NearToken,
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+struct BaselineMinerProposal {
+    proposal_hash: String,
+    answer: bool,
+    is_revealed: bool,
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+struct BaselineRequest {
+    sender: AccountId,
+    request_id: String,
+    start_time: u64,
+    miners_proposals: LookupMap<AccountId, BaselineMinerProposal>,
+    validators_proposals: LookupMap<AccountId, ValidatorProposal>,
+    votes_for_miners: LookupMap<AccountId, i32>,
+    miner_keys: Vec<AccountId>,
+    top_ten: Vec<(AccountId, i32)>,
+}
+
+fn setup_contract() -> Contract {
+    Environment::with_account(OWNER.parse().unwrap()).create();
+    let mut contract = Contract::new();
+
+    register_protocol_and_request(&mut contract);
+    register_and_commit_miners(&mut contract, &generate_miners(2), 0);
+
+    contract
+}
+
+// @dev Writes the state of the baseline contract with a registered protocol, a request revealed by two miners and a validator.
+// Dropping the state flushes its collections to storage
+fn write_baseline_state() {
+    Environment::with_account(CONTRACT_ACCOUNT_ID.parse().unwrap()).create();
+
+    let protocol = get_default_protocol_account();
+    let mut state = BaselineContract {
+        protocols: LookupMap::new(b"protocols".to_vec()),
+        requests: LookupMap::new(b"requests".to_vec()),
+        miners: LookupMap::new(b"miners".to_vec()),
+        validators: LookupMap::new(b"validators".to_vec()),
+    };
+
+    state.protocols.insert(
+        protocol.clone(),
+        BaselineProtocol {
+            account: protocol.clone(),
+            culture: DEFAULT_CULTURE.to_string(),
+            modules: vec![Module::TextPrompting],
+            registration_fee: DEFAULT_DEPOSIT_PROTOCOL,
+        },
+    );
+    // @dev Every baseline request shared the same proposal and vote maps
+    let miners = generate_miners(2);
+    let mut miners_proposals = LookupMap::new(b"miner_proposal".to_vec());
+    let mut votes_for_miners = LookupMap::new(b"votes_miners".to_vec());
+
+    for miner in &miners {
+        let proposal = BaselineMinerProposal {
+            proposal_hash: DEFAULT_MINER_ANSWER.to_string(),
+            answer: true,
+            is_revealed: true,
+        };
+        miners_proposals.insert(miner.clone(), proposal);
+        votes_for_miners.insert(miner.clone(), 1);
+        state.miners.insert(miner.clone(), DEFAULT_DEPOSIT_MINER);
+    }
+
+    state.requests.insert(
+        BASELINE_REQUEST_ID.to_string(),
+        BaselineRequest {
+            sender: protocol,
+            request_id: BASELINE_REQUEST_ID.to_string(),
+            start_time: env::block_timestamp(),
+            miners_proposals,
+            validators_proposals: LookupMap::new(b"validator_proposal".to_vec()),
+            votes_for_miners,
+            miner_keys: miners.clone(),
+            top_ten: miners.iter().map(|miner| (miner.clone(), 1)).collect(),
+        },
+    );
+    state.validators.insert(get_default_validator_account(), DEFAULT_DEPOSIT_VALIDATOR);
+
+    env::storage_write(STATE_KEY, &borsh::to_vec(&state).unwrap());
+}
+
+#[test]
+fn test_migrate_baseline_state() {
+    write_baseline_state();
+
+    let contract = Contract::migrate();
+
+    assert_logs(vec![Log::Event {
+        event_name: "state_migrated".to_string(),
+        data: vec![("version", json!["V1"])],
+    }]);
+    assert_eq!(contract.get_state_version(), StateVersion::V1);
+    assert_eq!(contract.get_owner(), CONTRACT_ACCOUNT_ID.parse::<AccountId>().unwrap());
+    assert_eq!(contract.get_treasury_balance(), NearToken::from_yoctonear(0));
+    assert_eq!(contract.get_miner_stake(generate_miners(2)[1].clone()), Some(DEFAULT_DEPOSIT_MINER));
+    assert_eq!(contract.get_validator_stake(get_default_validator_account()), Some(DEFAULT_DEPOSIT_VALIDATOR));
+    assert!(contract.is_protocol_registered(get_default_protocol_account()));
+    assert_eq!(contract.get_phase_schedule(get_default_protocol_account()), Some(DEFAULT_PHASE_SCHEDULE));
+
+    let request = contract.get_request(BASELINE_REQUEST_ID.to_string()).unwrap();
+    assert_eq!(request.sender, get_default_protocol_account());
+    assert_eq!(request.stage, RequestState::Ended);
+    assert_eq!(request.bounty, NearToken::from_yoctonear(0));
+    assert_eq!(request.options, vec!["Yes".to_string(), "No".to_string()]);
+}
+
+#[test]
+fn test_migrate_baseline_requests_are_closed() {
+    write_baseline_state();
+
+    Environment::with_account(CONTRACT_ACCOUNT_ID.parse().unwrap())
+        .with_block_timestamp(REVEAL_TOPTEN_TIME)
+        .create();
+    let mut contract = Contract::migrate();
+
+    // @dev The shared proposals and votes can't be split by request, so none of them is kept
+    let miners = generate_miners(2);
+    let request = contract.get_request(BASELINE_REQUEST_ID.to_string()).unwrap();
+    assert_eq!(request.stage, RequestState::Ended);
+    assert_eq!(request.miners_committed, 0);
+    assert_eq!(request.miners_revealed, 0);
+    assert_eq!(request.top_ten, Some(vec![(miners[0].clone(), 1), (miners[1].clone(), 1)]));
+    assert_eq!(request.decision, None);
+    assert_eq!(contract.get_votes_for_miner(BASELINE_REQUEST_ID.to_string(), miners[0].clone()), None);
+
+    assert_eq!(contract.finalize_request(BASELINE_REQUEST_ID.to_string()), FinalizeResult::AlreadyFinalized);
+    assert_eq!(contract.settle_miners(BASELINE_REQUEST_ID.to_string()), SettleResult::AlreadySettled);
+    assert_eq!(contract.settle_validators(BASELINE_REQUEST_ID.to_string()), SettleResult::AlreadySettled);
+}
+
+#[test]
+#[should_panic(expected = "Not at CommitMiners stage")]
+fn test_migrate_when_baseline_request_is_in_commit_miners() {
+    write_baseline_state();
+
+    // @dev The request was created at the time of the migration, so its commit stage would still be running
+    let mut contract = Contract::migrate();
+    assert_eq!(contract.get_request(BASELINE_REQUEST_ID.to_string()).unwrap().stage, RequestState::Ended);
+
+    let miner = generate_miners(1)[0].clone();
+    Environment::with_account(miner).create();
+    contract.commit_by_miner(BASELINE_REQUEST_ID.to_string(), DEFAULT_MINER_ANSWER.to_string());
+}
+
+#[test]
+fn test_migrated_baseline_state_keeps_working() {
+    write_baseline_state();
+
+    let mut contract = Contract::migrate();
+
+    // @dev The question of the baseline request can be asked again, since nonces restart at 0 with ids that can't be baseline ids
+    Environment::with_account(get_default_protocol_account()).create();
+    assert_eq!(contract.get_request_nonce(get_default_protocol_account()), 0);
+    assert_eq!(
+        contract.get_legacy_request_id(get_default_protocol_account(), BASELINE_MESSAGE.to_string()),
+        BASELINE_REQUEST_ID
+    );
+    let result = contract.request_governance_decision(BASELINE_MESSAGE.to_string(), None);
+
+    let request_id = contract.get_request_id(get_default_protocol_account(), BASELINE_MESSAGE.to_string(), 0);
+    assert_ne!(request_id, BASELINE_REQUEST_ID);
+    assert_eq!(result, RegisterRequestResult::Success(request_id.clone()));
+    let miner = generate_miners(1)[0].clone();
+
+    Environment::with_account(miner).create();
+    let result = contract.commit_by_miner(request_id.clone(), DEFAULT_MINER_ANSWER.to_string());
+    assert_eq!(result, CommitMinerResult::Success);
+
+    let request = contract.get_request(request_id).unwrap();
+    assert_eq!(request.miners_committed, 1);
+}
+
+#[test]
+fn test_backfill_registry() {
+    write_baseline_state();
+
+    let mut contract = Contract::migrate();
+    assert_eq!(contract.get_miner_count(), 0);
+    assert_eq!(contract.get_validator_count(), 0);
+
+    let miners = generate_miners(3);
+    assert_eq!(contract.backfill_registry(miners, vec![get_default_validator_account()]), 3);
+
+    assert_logs(vec![
+        Log::Event {
+            event_name: "state_migrated".to_string(),
+            data: vec![("version", json!["V1"])],
+        },
+        Log::Message("Accounts added to the registry: 2 miners, 1 validators".to_string()),
+    ]);
+    assert_eq!(contract.get_miner_count(), 2);
+    assert_eq!(contract.get_validator_count(), 1);
+    assert_eq!(
+        contract.get_miners(0, 10),
+        generate_miners(2)
+            .into_iter()
+            .map(|account| ParticipantView {
+                account,
+                stake: DEFAULT_DEPOSIT_MINER,
+                registered_at: 0,
+            })
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(contract.backfill_registry(generate_miners(2), vec![get_default_validator_account()]), 0);
+}
+
+#[test]
+#[should_panic(expected = "Only an account with one of the roles [Owner, Admin] can call this method")]
+fn test_backfill_registry_when_caller_is_not_an_admin() {
+    write_baseline_state();
+
+    let mut contract = Contract::migrate();
+
+    Environment::with_account(get_default_protocol_account()).create();
+    contract.backfill_registry(generate_miners(2), vec![]);
+}
+
+#[test]
+#[should_panic(expected = "At most 100 accounts can be backfilled at once")]
+fn test_backfill_registry_when_page_is_too_large() {
+    write_baseline_state();
+
+    let mut contract = Contract::migrate();
+    contract.backfill_registry(generate_miners(100), vec![get_default_validator_account()]);
+}
+
+#[test]
+fn test_migrate_when_state_is_current() {
+    let contract = setup_contract();
+    env::state_write(&contract);
+    drop(contract);
+
+    Environment::with_account(CONTRACT_ACCOUNT_ID.parse().unwrap()).create();
+    let contract = Contract::migrate();
+
+    assert_logs(vec![Log::Message("Contract state already migrated".to_string())]);
+    assert_eq!(contract.get_state_version(), StateVersion::V1);
+    assert!(contract.get_request(DEFAULT_REQUEST_ID.to_string()).is_some());
+}
+
+#[test]
+fn test_upgrade() {
+    let mut contract = setup_contract();
+
+    Environment::with_account(OWNER.parse().unwrap())
+        .with_attached_deposit(NearToken::from_yoctonear(0))
+        .with_input(b"\0asm".to_vec())
+        .create();
+    contract.upgrade();
+
+    assert_function_calls(vec![(CONTRACT_ACCOUNT_ID.parse().unwrap(), "migrate")]);
+}
+
+#[test]
+#[should_panic(expected = "Only an account with one of the roles [Owner] can call this method")]
+fn test_upgrade_when_caller_is_not_the_owner() {
+    let mut contract = setup_contract();
+
+    Environment::with_account(get_default_protocol_account()).with_input(b"\0asm".to_vec()).create();
+    contract.upgrade();
+}
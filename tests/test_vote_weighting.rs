@@ -0,0 +1,109 @@
+use near_sdk::{AccountId, NearToken};
+
+use common::constants::{DEFAULT_REQUEST_ID, REVEAL_TOPTEN_TIME};
+use common::environment::Environment;
+use common::lifecycle::{
+    generate_miners, generate_validators, register_and_commit_miners, register_and_commit_validator, register_protocol_and_request, reveal_miners,
+    reveal_validator,
+};
+use common::types::Log;
+use common::utils::assert_logs;
+
+use earthmind_rs::{Config, Contract, UpdateConfigResult, VoteWeighting, DEFAULT_CONFIG};
+
+pub mod common;
+
+// @dev Two validators with the minimum stake rank the first ten miners and a validator with
+// three times the minimum stake ranks the eleventh miner first instead of the first one
+fn setup_weighted_request(vote_weighting: VoteWeighting) -> (Contract, Vec<AccountId>) {
+    setup_weighted_request_with_config(Config {
+        vote_weighting,
+        ..DEFAULT_CONFIG
+    })
+}
+
+fn setup_weighted_request_with_config(config: Config) -> (Contract, Vec<AccountId>) {
+    let mut contract = Contract::new();
+    register_protocol_and_request(&mut contract);
+
+    let miners = generate_miners(11);
+    register_and_commit_miners(&mut contract, &miners, 0);
+    reveal_miners(&mut contract, &miners, 0);
+
+    let heavy_ranking: Vec<AccountId> = miners[10..].iter().chain(&miners[1..10]).cloned().collect();
+
+    let validators = generate_validators(3);
+    register_and_commit_validator(&mut contract, &validators[0], &miners[..10]);
+    register_and_commit_validator(&mut contract, &validators[1], &miners[..10]);
+    register_and_commit_validator(&mut contract, &validators[2], &heavy_ranking);
+
+    Environment::with_account(validators[2].clone())
+        .with_attached_deposit(NearToken::from_near(20))
+        .create();
+    contract.increase_validator_stake();
+
+    Environment::with_account(contract.get_owner()).create();
+    assert_eq!(contract.update_config(config), UpdateConfigResult::Success);
+
+    reveal_validator(&mut contract, &validators[0], &miners[..10]);
+    reveal_validator(&mut contract, &validators[1], &miners[..10]);
+    reveal_validator(&mut contract, &validators[2], &heavy_ranking);
+
+    (contract, miners)
+}
+
+#[test]
+fn test_votes_are_weighted_by_validator_stake() {
+    let (contract, miners) = setup_weighted_request(VoteWeighting::Linear);
+
+    Environment::with_account(miners[0].clone()).create();
+
+    contract.votes_for_miner(DEFAULT_REQUEST_ID.to_string(), miners[0].clone());
+    contract.votes_for_miner(DEFAULT_REQUEST_ID.to_string(), miners[1].clone());
+    contract.votes_for_miner(DEFAULT_REQUEST_ID.to_string(), miners[10].clone());
+
+    assert_logs(vec![
+        Log::Message("miner1.near have 20 votes".to_string()),
+        Log::Message("miner2.near have 45 votes".to_string()),
+        Log::Message("miner11.near have 30 votes".to_string()),
+    ]);
+}
+
+#[test]
+fn test_get_top_10_voters_ranks_by_weighted_votes() {
+    let (mut contract, miners) = setup_weighted_request(VoteWeighting::Linear);
+
+    Environment::with_account(miners[0].clone()).with_block_timestamp(REVEAL_TOPTEN_TIME).create();
+
+    contract.finalize_request(DEFAULT_REQUEST_ID.to_string());
+
+    let top_ten = contract.get_top_10_voters(DEFAULT_REQUEST_ID.to_string());
+
+    let position = |account: &AccountId| top_ten.iter().position(|(miner, _)| miner == account);
+
+    assert_eq!(top_ten.len(), 10);
+    assert!(top_ten.contains(&(miners[10].clone(), 30)));
+    assert!(position(&miners[10]) < position(&miners[0]));
+    assert_eq!(position(&miners[9]), None);
+}
+
+#[test]
+fn test_vote_weighting_is_switched_with_the_config() {
+    let (contract, miners) = setup_weighted_request(VoteWeighting::SquareRoot);
+
+    // @dev The square root of three units of stake rounds down to a weight of 1
+    assert_eq!(contract.get_votes_for_miner(DEFAULT_REQUEST_ID.to_string(), miners[0].clone()), Some(20));
+    assert_eq!(contract.get_votes_for_miner(DEFAULT_REQUEST_ID.to_string(), miners[10].clone()), Some(10));
+}
+
+#[test]
+fn test_vote_weight_cap_is_updated_with_the_config() {
+    let (contract, miners) = setup_weighted_request_with_config(Config {
+        vote_weighting: VoteWeighting::Capped,
+        vote_weight_cap: 2,
+        ..DEFAULT_CONFIG
+    });
+
+    // @dev The three units of stake of the heavy validator are capped to a weight of 2
+    assert_eq!(contract.get_votes_for_miner(DEFAULT_REQUEST_ID.to_string(), miners[10].clone()), Some(20));
+}
@@ -1,3 +1,4 @@
+use near_sdk::NearToken;
 use serde_json::json;
 
 use common::constants::{
@@ -27,7 +28,7 @@ fn test_votes_for_miner_using_one_validator() {
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
     contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -76,7 +77,7 @@ fn test_votes_for_miner_using_one_validator() {
 
     for miners in registered_miners {
         Environment::with_account(miners.clone()).with_block_timestamp(REVEAL_MINER_TIME).create();
-        let answer = true;
+        let answer = 0;
         let message = "It's a cool NFT";
         let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, message.to_string());
         assert_eq!(result, RevealMinerResult::Success);
@@ -128,7 +129,7 @@ fn test_votes_for_miner_using_one_validator() {
                 ("message", json![message]),
             ],
         },
-        Log::Message("miner1.near have 1 votes".to_string()),
+        Log::Message("miner1.near have 10 votes".to_string()),
     ]);
 }
 
@@ -144,7 +145,7 @@ fn test_vote_for_miners_with_multiple_validators() {
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
     contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -194,7 +195,7 @@ fn test_vote_for_miners_with_multiple_validators() {
 
     for miners in registered_miners {
         Environment::with_account(miners.clone()).with_block_timestamp(REVEAL_MINER_TIME).create();
-        let answer = true;
+        let answer = 0;
         let message = "It's a cool NFT";
         let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, message.to_string());
         assert_eq!(result, RevealMinerResult::Success);
@@ -238,7 +239,7 @@ fn test_vote_for_miners_with_multiple_validators() {
                 ("message", json![message]),
             ],
         },
-        Log::Message("miner1.near have 1 votes".to_string()),
+        Log::Message("miner1.near have 10 votes".to_string()),
     ]);
 
     //@dev Second validator
@@ -258,7 +259,7 @@ fn test_vote_for_miners_with_multiple_validators() {
         .with_block_timestamp(COMMIT_VALIDATOR_TIME)
         .create();
 
-    let validator2_answer = "773fbc1648f4f84994f716ce22b8a990a6febbe4516d5d7d444fbad8f8ed149f";
+    let validator2_answer = "ee8fa3b01a26c5c74401caa24017449edc1c99b905f2d09ce66c97aebe976abb";
     contract.commit_by_validator(DEFAULT_REQUEST_ID.to_string(), validator2_answer.to_string());
 
     assert_logs(vec![Log::Event {
@@ -285,7 +286,7 @@ fn test_vote_for_miners_with_multiple_validators() {
                 ("message", json![message]),
             ],
         },
-        Log::Message("miner1.near have 2 votes".to_string()),
+        Log::Message("miner1.near have 20 votes".to_string()),
     ]);
 }
 
@@ -301,7 +302,7 @@ fn test_get_top_10_voters() {
 
     let modules = vec![Module::TextPrompting, Module::ObjectRecognition];
     contract.register_protocol(DEFAULT_CULTURE.to_string(), modules);
-    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string());
+    contract.request_governance_decision(DEFAULT_MESSAGE_TO_REQUEST.to_string(), None);
     assert_logs(vec![
         Log::Event {
             event_name: "register_protocol".to_string(),
@@ -373,7 +374,7 @@ fn test_get_top_10_voters() {
 
     for miners in registered_miners {
         Environment::with_account(miners.clone()).with_block_timestamp(REVEAL_MINER_TIME).create();
-        let answer = true;
+        let answer = 0;
         let message = "It's a cool NFT";
         let result = contract.reveal_by_miner(DEFAULT_REQUEST_ID.to_string(), answer, message.to_string());
         assert_eq!(result, RevealMinerResult::Success);
@@ -403,7 +404,7 @@ fn test_get_top_10_voters() {
         .with_block_timestamp(COMMIT_VALIDATOR_TIME)
         .create();
 
-    let validator2_answer = "773fbc1648f4f84994f716ce22b8a990a6febbe4516d5d7d444fbad8f8ed149f";
+    let validator2_answer = "ee8fa3b01a26c5c74401caa24017449edc1c99b905f2d09ce66c97aebe976abb";
     contract.commit_by_validator(DEFAULT_REQUEST_ID.to_string(), validator2_answer.to_string());
 
     assert_logs(vec![Log::Event {
@@ -415,7 +416,7 @@ fn test_get_top_10_voters() {
         .with_block_timestamp(COMMIT_VALIDATOR_TIME)
         .create();
 
-    let answer_validator3 = "88ead563a267bca8b37e7933812f84c47e8d757fdf9ab4cdf92a4cdde3f63a86";
+    let answer_validator3 = "24d2cb4eb0e9bcdbfaceca2b43eb85b44cb158f16fbba2dc2183c17b18ff657d";
     contract.commit_by_validator(DEFAULT_REQUEST_ID.to_string(), answer_validator3.to_string());
 
     assert_logs(vec![Log::Event {
@@ -475,10 +476,32 @@ fn test_get_top_10_voters() {
 
     Environment::with_account(validator_1).with_block_timestamp(REVEAL_TOPTEN_TIME).create();
 
+    contract.finalize_request(DEFAULT_REQUEST_ID.to_string());
+
     let top_ten = contract.get_top_10_voters(DEFAULT_REQUEST_ID.to_string());
 
-    assert_logs(vec![Log::Event {
-        event_name: "topten_miners".to_string(),
-        data: vec![("request_id", json![DEFAULT_REQUEST_ID]), ("topten", json![top_ten])],
-    }]);
+    assert_logs(vec![
+        Log::Event {
+            event_name: "topten_miners".to_string(),
+            data: vec![("request_id", json![DEFAULT_REQUEST_ID]), ("topten", json![top_ten])],
+        },
+        Log::Event {
+            event_name: "decision_finalized".to_string(),
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("decision", json!({ "option": 0, "votes": [10, 0], "value": null })),
+            ],
+        },
+        Log::Event {
+            event_name: "distribute_rewards".to_string(),
+            data: vec![
+                ("request_id", json![DEFAULT_REQUEST_ID]),
+                ("miners", json![group_registered_miners()]),
+                ("miner_reward", json![NearToken::from_millinear(420)]),
+                ("validators", json![vec![VALIDATOR_1, VALIDATOR_2, VALIDATOR_3]]),
+                ("validator_reward", json![NearToken::from_millinear(600)]),
+                ("bounty", json![DEFAULT_DEPOSIT_PROTOCOL]),
+            ],
+        },
+    ]);
 }